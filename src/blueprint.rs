@@ -0,0 +1,167 @@
+//! Detection of TON Blueprint projects, used by `muton init` to fill in targets
+//! and per-contract test commands.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Source extensions of contracts compiled by Blueprint wrappers
+const CONTRACT_EXTENSIONS: &[&str] = &["fc", "func", "tact", "tolk"];
+/// Directory of the library sources Blueprint templates ship, such as
+/// `stdlib.fc`, which wrappers list alongside the contracts they compile
+const LIBRARY_DIR: &str = "contracts/imports/";
+/// Test command for contracts without a matching spec file
+pub const DEFAULT_TEST_CMD: &str = "npx blueprint test";
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BlueprintProject {
+    /// Contract sources named by a wrapper, relative to the project root
+    pub contracts: Vec<String>,
+    /// Spec files that compile each contract, relative to the project root
+    pub specs: BTreeMap<String, Vec<String>>,
+}
+
+impl BlueprintProject {
+    /// Detect a Blueprint project by its `blueprint.config.ts` or
+    /// `wrappers/*.compile.ts` files, reading only files on disk
+    pub fn detect(root: &Path) -> Option<Self> {
+        let wrappers = compile_wrappers(root);
+        if wrappers.is_empty() && !root.join("blueprint.config.ts").is_file() {
+            return None;
+        }
+        let specs = list_files(&root.join("tests"), ".spec.ts")
+            .into_iter()
+            .filter_map(|name| {
+                let text = fs::read_to_string(root.join("tests").join(&name)).ok()?;
+                Some((format!("tests/{name}"), text))
+            })
+            .collect::<Vec<_>>();
+
+        let mut project = BlueprintProject::default();
+        for (wrapper, contracts) in wrappers {
+            let wrapper_specs = specs
+                .iter()
+                .filter(|(_, text)| uses_wrapper(text, &wrapper))
+                .map(|(path, _)| path.clone());
+            for contract in contracts {
+                if !project.contracts.contains(&contract) {
+                    project.contracts.push(contract.clone());
+                }
+                let entry = project.specs.entry(contract).or_default();
+                for spec in wrapper_specs.clone() {
+                    if !entry.contains(&spec) {
+                        entry.push(spec);
+                    }
+                }
+            }
+        }
+        project.specs.retain(|_, specs| !specs.is_empty());
+        project.contracts.sort();
+        Some(project)
+    }
+
+    /// Include globs for each contract language used in the project
+    pub fn include_globs(&self) -> Vec<String> {
+        CONTRACT_EXTENSIONS
+            .iter()
+            .filter(|ext| self.contracts.iter().any(|c| has_extension(c, ext)))
+            .map(|ext| format!("contracts/**/*.{ext}"))
+            .collect()
+    }
+
+    /// Fill in a freshly generated config: set `[targets].include` and a default
+    /// test command, and add a `[[test.per_target]]` rule per tested contract.
+    /// Library sources under `contracts/imports/` are ignored, by that path
+    /// alone since ignore patterns match anywhere in a target's path.
+    pub fn apply_to_config(&self, config: &str) -> String {
+        let include = self
+            .include_globs()
+            .iter()
+            .map(|glob| format!("\"{glob}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut out = String::new();
+        for line in config.lines() {
+            out.push_str(line);
+            out.push('\n');
+            match line.trim() {
+                "[targets]" if !include.is_empty() => {
+                    out.push_str(&format!("include = [{include}]\n"));
+                    out.push_str(&format!("ignore = [\"{LIBRARY_DIR}\"]\n"));
+                }
+                "[test]" => out.push_str(&format!("cmd = \"{DEFAULT_TEST_CMD}\"\n")),
+                _ => {}
+            }
+        }
+        for (contract, specs) in &self.specs {
+            if contract.starts_with(LIBRARY_DIR) {
+                continue;
+            }
+            out.push_str(&format!(
+                "\n[[test.per_target]]\nglob = \"{contract}\"\ncmd = \"npx jest {}\"\n",
+                specs.join(" ")
+            ));
+        }
+        out
+    }
+}
+
+/// Map each `wrappers/<Name>.compile.ts` to the contract sources it names
+fn compile_wrappers(root: &Path) -> Vec<(String, Vec<String>)> {
+    let dir = root.join("wrappers");
+    list_files(&dir, ".compile.ts")
+        .into_iter()
+        .filter_map(|name| {
+            let text = fs::read_to_string(dir.join(&name)).ok()?;
+            let wrapper = name.strip_suffix(".compile.ts")?.to_string();
+            Some((wrapper, contract_paths(&text)))
+        })
+        .collect()
+}
+
+/// Quoted contract paths in a compile wrapper, from its `target`, `targets` or
+/// `entrypoint` fields
+fn contract_paths(text: &str) -> Vec<String> {
+    let mut paths = Vec::new();
+    for quote in ['\'', '"', '`'] {
+        for (i, literal) in text.split(quote).enumerate() {
+            let is_contract = CONTRACT_EXTENSIONS
+                .iter()
+                .any(|ext| has_extension(literal, ext));
+            if i % 2 == 1 && is_contract && !literal.contains(char::is_whitespace) {
+                let path = literal.trim_start_matches("./").to_string();
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+        }
+    }
+    paths
+}
+
+/// Whether a spec compiles the wrapper with `compile('Name')` or imports it
+fn uses_wrapper(spec: &str, wrapper: &str) -> bool {
+    ['\'', '"'].iter().any(|q| {
+        spec.contains(&format!("compile({q}{wrapper}{q})"))
+            || spec.contains(&format!("wrappers/{wrapper}{q}"))
+            || spec.contains(&format!("build/{wrapper}/"))
+    })
+}
+
+fn has_extension(path: &str, ext: &str) -> bool {
+    Path::new(path).extension().is_some_and(|e| e == ext)
+}
+
+/// Sorted names of the files in `dir` ending with `suffix`
+fn list_files(dir: &Path, suffix: &str) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.ends_with(suffix))
+        .collect();
+    names.sort();
+    names
+}
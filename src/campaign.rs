@@ -0,0 +1,259 @@
+//! Mutation campaigns for `muton run`. Targets are grouped by test command and
+//! each group gets a runner with its own baseline, as in the shared runtime,
+//! and test commands that write their report to a file print it too. When a
+//! [`Selection`] narrows the run, the shared campaign loop runs over a scratch
+//! database holding only the selected mutants, and their outcomes are copied
+//! back.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use log::{error, info, warn};
+use mewt::core::cli::{RunArgs, TestArgs};
+use mewt::core::cmds;
+use mewt::core::runner::TestRunner;
+use mewt::types::config::{config, resolve_test_for_path};
+use mewt::types::{AppResult, CampaignSummary, Mutant, Outcome, Status, StoreResult, Target};
+use mewt::{LanguageRegistry, SqlStore};
+use tempfile::TempDir;
+
+use crate::languages;
+use crate::languages::scope::Selection;
+use crate::test_output::print_output_file;
+
+/// Test the untested and timed-out mutants of `targets` that the selection
+/// picks out, returning the campaign-wide counts afterwards
+pub async fn execute_run(
+    args: &RunArgs,
+    store: &SqlStore,
+    running: Arc<AtomicBool>,
+    registry: Arc<LanguageRegistry>,
+    targets: Vec<Target>,
+    selection: &Selection,
+) -> AppResult<CampaignSummary> {
+    let mutations = config().resolve_mutations(args.mutations.as_deref());
+    let test_cmd = config().resolve_test_cmd(args.test_cmd.as_deref());
+    let test_timeout = config().resolve_test_timeout(args.test_timeout);
+
+    let mut groups: BTreeMap<(String, Option<u32>), Vec<Target>> = BTreeMap::new();
+    for target in targets {
+        match resolve_test_for_path(&target.path, test_cmd.as_deref(), test_timeout) {
+            (Some(cmd), timeout) => groups
+                .entry((print_output_file(&cmd), timeout))
+                .or_default()
+                .push(target),
+            (None, _) => warn!("No test command provided for target {}", target.display()),
+        }
+    }
+
+    for ((cmd, timeout), targets) in groups {
+        if !running.load(Ordering::SeqCst) {
+            warn!("Mutation campaign cancelled before execution");
+            break;
+        }
+        let selected = if selection.is_everything() {
+            None
+        } else {
+            Some(SelectedMutants::copy(store, selection, targets.clone()).await?)
+        };
+        let (campaign_store, targets) = match &selected {
+            Some(selected) => (selected.store.clone(), selected.targets.clone()),
+            None => (store.clone(), targets),
+        };
+        if targets.is_empty() {
+            info!("No selected mutants to test for test command: {cmd}");
+            continue;
+        }
+        let mut runner = TestRunner::new_with_baseline(
+            cmd,
+            timeout.or(config().test().timeout()),
+            Arc::clone(&running),
+            campaign_store,
+            args.comprehensive,
+            args.verbose,
+            Arc::clone(&registry),
+        )
+        .await?;
+        let result = runner
+            .run_mutation_campaign(targets, mutations.as_ref().map(|m| m.join(",")))
+            .await;
+        if let Some(selected) = &selected {
+            selected.copy_outcomes(store).await?;
+        }
+        result?;
+    }
+    Ok(store.get_campaign_summary().await?)
+}
+
+/// Re-test mutants by id, as the shared runtime does, with each test command
+/// set up to print a report it writes with `--outputFile`
+pub async fn execute_test(
+    args: TestArgs,
+    store: &SqlStore,
+    running: Arc<AtomicBool>,
+    registry: Arc<LanguageRegistry>,
+) -> AppResult<()> {
+    let test_cmd = config().resolve_test_cmd(args.test_cmd.as_deref());
+    let test_timeout = config().resolve_test_timeout(args.test_timeout);
+    let mut groups: BTreeMap<(String, Option<u32>), Vec<String>> = BTreeMap::new();
+    let ids = read_mutant_ids(&args)?;
+    if ids.is_empty() {
+        return Err(
+            io::Error::new(io::ErrorKind::InvalidInput, "No valid mutant IDs provided").into(),
+        );
+    }
+    for id in ids {
+        let target = match store.get_mutant(id).await {
+            Ok(mutant) => store.get_target(mutant.target_id).await?,
+            Err(e) => {
+                error!("Failed to get mutant {id}: {e}");
+                continue;
+            }
+        };
+        match resolve_test_for_path(&target.path, test_cmd.as_deref(), test_timeout) {
+            (Some(cmd), timeout) => groups
+                .entry((print_output_file(&cmd), timeout))
+                .or_default()
+                .push(id.to_string()),
+            (None, _) => warn!("No test command provided for target {}", target.display()),
+        }
+    }
+    for ((cmd, timeout), ids) in groups {
+        let args = TestArgs {
+            ids: Some(ids.join(",")),
+            ids_file: None,
+            test_cmd: Some(cmd.clone()),
+            test_timeout: timeout,
+            verbose: args.verbose,
+        };
+        cmds::execute_test(
+            args,
+            store.clone(),
+            Arc::clone(&running),
+            Arc::clone(&registry),
+            Some(cmd),
+            timeout,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Mutant ids from `--ids-file` (a file, or `-` for stdin) or else `--ids`,
+/// separated by commas or whitespace
+fn read_mutant_ids(args: &TestArgs) -> io::Result<Vec<i64>> {
+    let input = match (&args.ids_file, &args.ids) {
+        (Some(path), _) if path == "-" => io::read_to_string(io::stdin())?,
+        (Some(path), _) => fs::read_to_string(path)?,
+        (None, Some(ids)) => ids.clone(),
+        (None, None) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Either --ids or --ids-file must be provided",
+            ));
+        }
+    };
+    let mut ids = Vec::new();
+    for token in input.split(|c: char| c.is_whitespace() || c == ',') {
+        match token.parse() {
+            Ok(id) => ids.push(id),
+            Err(_) if token.is_empty() => {}
+            Err(_) => warn!("Skipping invalid mutant ID: {token}"),
+        }
+    }
+    Ok(ids)
+}
+
+/// Targets with untested or timed-out mutants, for `run` without targets
+pub async fn targets_to_test(store: &SqlStore) -> StoreResult<Vec<Target>> {
+    let (mutants, _, _) = store.get_mutants_to_test().await?;
+    let mut target_ids: Vec<i64> = mutants.iter().map(|m| m.target_id).collect();
+    target_ids.sort_unstable();
+    target_ids.dedup();
+    let mut targets = Vec::new();
+    for target_id in target_ids {
+        targets.push(store.get_target(target_id).await?);
+    }
+    Ok(targets)
+}
+
+/// The selected untested and timed-out mutants of a group of targets, copied
+/// into a scratch database so that the shared campaign loop tests only them
+struct SelectedMutants {
+    store: SqlStore,
+    targets: Vec<Target>,
+    /// Each copied mutant's scratch id with its id in the project database
+    ids: Vec<(i64, i64)>,
+    _dir: TempDir,
+}
+
+impl SelectedMutants {
+    async fn copy(
+        store: &SqlStore,
+        selection: &Selection,
+        targets: Vec<Target>,
+    ) -> AppResult<Self> {
+        let dir = tempfile::tempdir()?;
+        let db = dir.path().join("selection.sqlite");
+        fs::File::create(&db)?;
+        let scratch = SqlStore::new(format!("sqlite:{}", db.display())).await?;
+
+        let mut copied = Vec::new();
+        let mut ids = Vec::new();
+        for target in targets {
+            let functions = languages::function_spans(&target);
+            let mut pending = Vec::new();
+            for mutant in selection.filter(&target, &functions, store.get_mutants(target.id).await?)
+            {
+                match store.get_outcome(mutant.id).await? {
+                    Some(outcome) if outcome.status != Status::Timeout => {}
+                    _ => pending.push(mutant),
+                }
+            }
+            if pending.is_empty() {
+                continue;
+            }
+            let target_id = scratch.add_target(target.clone()).await?;
+            for mutant in pending {
+                let id = mutant.id;
+                if let Some(scratch_id) = scratch
+                    .add_mutant(Mutant {
+                        target_id,
+                        ..mutant
+                    })
+                    .await?
+                {
+                    ids.push((scratch_id, id));
+                }
+            }
+            copied.push(Target {
+                id: target_id,
+                ..target
+            });
+        }
+        Ok(Self {
+            store: scratch,
+            targets: copied,
+            ids,
+            _dir: dir,
+        })
+    }
+
+    /// Record the outcomes the campaign stored in the scratch database
+    async fn copy_outcomes(&self, store: &SqlStore) -> StoreResult<()> {
+        for &(scratch_id, id) in &self.ids {
+            if let Some(outcome) = self.store.get_outcome(scratch_id).await? {
+                store
+                    .add_outcome(Outcome {
+                        mutant_id: id,
+                        ..outcome
+                    })
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
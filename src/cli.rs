@@ -0,0 +1,149 @@
+//! Muton's command line: the shared runtime's commands, with muton's own flags
+//! added to `mutate` and `run`, and the commands muton adds.
+
+use clap::{Args, Parser, Subcommand};
+use mewt::core::cli::{
+    MutateArgs, PrintArgs, PurgeArgs, ResultsArgs, RunArgs, StatusArgs, TestArgs,
+};
+
+use crate::config::ScopeConfig;
+use crate::languages::scope::Selection;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "muton",
+    version,
+    about = "Mutation Testing Framework for TON Blockchain Languages - muton"
+)]
+pub struct Cli {
+    /// Path to the config file. The directory containing the config file becomes
+    /// the working directory, and relative paths in the config are resolved from there.
+    #[arg(long, global = true)]
+    pub config: Option<String>,
+
+    /// Location of the sqlite database
+    #[arg(long, global = true)]
+    pub db: Option<String>,
+
+    /// Logging level (overrides env/config). One of: trace, debug, info, warn, error
+    #[arg(long = "log.level", global = true)]
+    pub log_level: Option<String>,
+
+    /// Logging color control: "on" to force colors, "off" to disable; omit for auto
+    #[arg(long = "log.color", global = true)]
+    pub log_color: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Initialize a new workspace (config + database)
+    Init,
+
+    /// Run a mutation testing campaign
+    Run(RunCommand),
+
+    /// Generate and save mutants for a target without running tests
+    Mutate(MutateCommand),
+
+    /// Clean the database of stale targets
+    Clean,
+
+    /// Show mutation testing results
+    Results(ResultsArgs),
+
+    /// Print various information about mutations and results
+    Print {
+        #[command(subcommand)]
+        command: PrintArgs,
+    },
+
+    /// Show campaign overview with per-file breakdown and aggregates
+    Status(StatusArgs),
+
+    /// (Re-)Test a specific mutant by ID
+    Test(TestArgs),
+
+    /// Purge targets, mutants, and outcomes from the database
+    Purge(PurgeArgs),
+
+    /// Show which tests killed each mutant, parsed from Jest JSON or TAP output
+    Effectiveness(EffectivenessArgs),
+
+    /// Skip untested mutants on lines no test executes, according to LCOV data
+    Coverage(CoverageArgs),
+}
+
+/// Arguments for the run command
+#[derive(Args, Debug)]
+pub struct RunCommand {
+    #[command(flatten)]
+    pub args: RunArgs,
+
+    #[command(flatten)]
+    pub selection: SelectionArgs,
+}
+
+/// Arguments for the mutate command
+#[derive(Args, Debug)]
+pub struct MutateCommand {
+    #[command(flatten)]
+    pub args: MutateArgs,
+
+    #[command(flatten)]
+    pub selection: SelectionArgs,
+}
+
+/// Arguments for the effectiveness command
+#[derive(Args, Debug)]
+pub struct EffectivenessArgs {
+    /// Output format: table or json
+    #[arg(long, default_value = "table", value_parser = ["table", "json"])]
+    pub format: String,
+}
+
+/// Arguments for the coverage command
+#[derive(Args, Debug)]
+pub struct CoverageArgs {
+    /// LCOV file to read. Can be repeated. Replaces config [coverage].lcov if provided.
+    #[arg(long, value_name = "FILE")]
+    pub lcov: Vec<String>,
+
+    /// List the mutants skipped for lack of coverage instead of marking more
+    #[arg(long, conflicts_with = "lcov")]
+    pub list: bool,
+}
+
+/// Flags that restrict `mutate` and `run` to part of each target
+#[derive(Args, Debug)]
+pub struct SelectionArgs {
+    /// Only mutate code inside this function, receiver or method (e.g. 'receive("withdraw")').
+    /// Can be repeated. Replaces config [scope].functions if provided.
+    #[arg(long = "function", value_name = "NAME")]
+    pub functions: Vec<String>,
+
+    /// Only mutate lines changed in the working tree since this git revision.
+    /// Replaces config [scope].since if provided.
+    #[arg(long, value_name = "REV")]
+    pub since: Option<String>,
+}
+
+impl SelectionArgs {
+    /// Combine the flags with the configured `[scope]`
+    pub fn resolve(&self, scope: &ScopeConfig) -> Selection {
+        scope.resolve(&self.functions, self.since.as_deref())
+    }
+}
+
+impl Command {
+    /// The selection given to `mutate` or `run`
+    pub fn selection(&self, scope: &ScopeConfig) -> Selection {
+        match self {
+            Command::Run(command) => command.selection.resolve(scope),
+            Command::Mutate(command) => command.selection.resolve(scope),
+            _ => Selection::default(),
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! Muton-specific settings, read from extra sections of `muton.toml` that the
+//! shared mewt config ignores.
+
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use log::warn;
+use mewt::types::config::get_config_path;
+use serde::Deserialize;
+
+use crate::languages::scope::Selection;
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ParseConfig {
+    /// Fail the run when a target does not parse cleanly
+    pub strict: Option<bool>,
+}
+
+impl ParseConfig {
+    pub fn strict(&self) -> bool {
+        self.strict.unwrap_or(false)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ScopeConfig {
+    /// Functions, receivers or methods to restrict mutation to (None = all)
+    pub functions: Option<Vec<String>>,
+    /// Git revision to restrict mutation to lines changed since (None = all)
+    pub since: Option<String>,
+}
+
+impl ScopeConfig {
+    /// Resolve the selection from `--function` and `--since`, each of which
+    /// replaces its configured value when given
+    pub fn resolve(&self, functions: &[String], since: Option<&str>) -> Selection {
+        Selection {
+            functions: if functions.is_empty() {
+                self.functions.clone().unwrap_or_default()
+            } else {
+                functions.to_vec()
+            },
+            since: since.map(str::to_string).or_else(|| self.since.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct CoverageConfig {
+    /// LCOV tracefiles whose unexecuted lines are skipped
+    pub lcov: Option<Vec<String>>,
+}
+
+impl CoverageConfig {
+    pub fn lcov(&self) -> &[String] {
+        self.lcov.as_deref().unwrap_or(&[])
+    }
+}
+
+/// Seconds a compiler run may take when no `timeout` is configured
+pub const DEFAULT_COMPILE_TIMEOUT: u64 = 60;
+
+/// Compiler command templates per language, where `{file}` is the source path
+/// and `{output}` an optional path for the compiled code
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct CompilerConfig {
+    pub func: Option<String>,
+    pub tact: Option<String>,
+    pub tolk: Option<String>,
+    /// Seconds one compiler run may take before it counts as failed
+    pub timeout: Option<u64>,
+}
+
+impl CompilerConfig {
+    /// Limit applied to every run of the configured commands
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout.unwrap_or(DEFAULT_COMPILE_TIMEOUT))
+    }
+
+    /// Whether a command is configured for any language
+    pub fn is_configured(&self) -> bool {
+        self.func.is_some() || self.tact.is_some() || self.tolk.is_some()
+    }
+
+    /// Get the compile command configured for a language, if any
+    pub fn command(&self, language: &str) -> Option<&str> {
+        match language.to_ascii_lowercase().as_str() {
+            "func" => self.func.as_deref(),
+            "tact" => self.tact.as_deref(),
+            "tolk" => self.tolk.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// A user-defined mutation: every node captured by a tree-sitter query is
+/// replaced with a template
+#[derive(Debug, Clone, Deserialize)]
+pub struct RuleConfig {
+    /// Language the rule applies to (e.g. "Tact")
+    pub language: String,
+    pub slug: String,
+    pub description: String,
+    /// "high", "medium" or "low"
+    pub severity: String,
+    /// Tree-sitter S-expression query
+    pub query: String,
+    /// Capture whose node is replaced; defaults to `target`
+    pub capture: Option<String>,
+    /// Replacement text, where `{name}` expands to the text of capture `name`
+    pub replacement: String,
+}
+
+impl RuleConfig {
+    pub fn capture(&self) -> &str {
+        self.capture.as_deref().unwrap_or("target")
+    }
+}
+
+/// A standalone rules file listed in `rule_files`
+#[derive(Debug, Clone, Deserialize, Default)]
+struct RulesFile {
+    rules: Option<Vec<RuleConfig>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct MutonConfig {
+    pub parse: Option<ParseConfig>,
+    pub scope: Option<ScopeConfig>,
+    /// Commands that check a mutant compiles
+    pub compile: Option<CompilerConfig>,
+    /// Commands whose compiled output identifies equivalent mutants
+    pub equivalence: Option<CompilerConfig>,
+    pub coverage: Option<CoverageConfig>,
+    /// Rules defined inline as `[[rules]]` tables
+    pub rules: Option<Vec<RuleConfig>>,
+    /// Extra TOML files holding `[[rules]]` tables, relative to the config file
+    pub rule_files: Option<Vec<String>>,
+}
+
+impl MutonConfig {
+    pub fn parse(&self) -> ParseConfig {
+        self.parse.clone().unwrap_or_default()
+    }
+
+    pub fn scope(&self) -> ScopeConfig {
+        self.scope.clone().unwrap_or_default()
+    }
+
+    pub fn compile(&self) -> CompilerConfig {
+        self.compile.clone().unwrap_or_default()
+    }
+
+    pub fn equivalence(&self) -> CompilerConfig {
+        self.equivalence.clone().unwrap_or_default()
+    }
+
+    pub fn coverage(&self) -> CoverageConfig {
+        self.coverage.clone().unwrap_or_default()
+    }
+
+    /// Collect the inline rules followed by the rules of each rules file
+    pub fn rules(&self) -> Vec<RuleConfig> {
+        let mut rules = self.rules.clone().unwrap_or_default();
+        for file in self.rule_files.as_deref().unwrap_or(&[]) {
+            let parsed = fs::read_to_string(file)
+                .map_err(|e| e.to_string())
+                .and_then(|contents| {
+                    toml::from_str::<RulesFile>(&contents).map_err(|e| e.to_string())
+                });
+            match parsed {
+                Ok(file_rules) => rules.extend(file_rules.rules.unwrap_or_default()),
+                Err(e) => warn!("Ignoring rules file {file}: {e}"),
+            }
+        }
+        rules
+    }
+
+    /// Read the muton sections of a config file, ignoring mewt's own sections
+    pub fn from_file(path: &Path) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+        match toml::from_str(&contents) {
+            Ok(cfg) => Some(cfg),
+            Err(e) => {
+                warn!("Ignoring muton settings in {}: {e}", path.display());
+                None
+            }
+        }
+    }
+}
+
+static MUTON_CONFIG: OnceLock<MutonConfig> = OnceLock::new();
+
+/// Get the muton settings from the config file located by mewt at startup
+pub fn muton_config() -> &'static MutonConfig {
+    MUTON_CONFIG.get_or_init(|| {
+        get_config_path()
+            .and_then(|path| MutonConfig::from_file(path))
+            .unwrap_or_default()
+    })
+}
@@ -0,0 +1,168 @@
+//! Coverage-guided skipping: mutants on lines that no test executes, according
+//! to LCOV data, get a `Skipped` outcome instead of a test run.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use mewt::SqlStore;
+use mewt::types::{Mutant, Outcome, Status, StoreResult, Target};
+
+use crate::languages::scope::Selection;
+use crate::skipped::{SkipReason, untested_mutants};
+
+/// Prefix of the output stored with mutants skipped for lack of coverage
+pub const NOT_COVERED: &str = "Not covered";
+
+/// Mutants skipped for lack of coverage
+pub const NOT_COVERED_SKIP: SkipReason = SkipReason {
+    prefix: NOT_COVERED,
+    title: "Not Covered",
+    summary: "are on lines no test executes",
+};
+
+/// Per-line execution counts of each source file in one or more LCOV files
+#[derive(Debug, Clone, Default)]
+pub struct LineCoverage {
+    files: BTreeMap<PathBuf, FileCoverage>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct FileCoverage {
+    /// The LCOV path resolved against the working directory, if the file exists
+    canonical: Option<PathBuf>,
+    lines: BTreeMap<u32, u64>,
+}
+
+impl LineCoverage {
+    /// Parse the `SF:` and `DA:<line>,<hits>` records of an LCOV tracefile
+    pub fn parse_lcov(text: &str) -> Self {
+        let mut coverage = Self::default();
+        coverage.add_lcov(text);
+        coverage
+    }
+
+    /// Merge the records of another LCOV tracefile, summing hits
+    pub fn add_lcov(&mut self, text: &str) {
+        let mut current: Option<PathBuf> = None;
+        for line in text.lines().map(str::trim) {
+            if let Some(path) = line.strip_prefix("SF:") {
+                current = Some(normalize(Path::new(path)));
+            } else if line == "end_of_record" {
+                current = None;
+            } else if let (Some(path), Some(data)) = (&current, line.strip_prefix("DA:")) {
+                let mut fields = data.split(',');
+                let line = fields.next().and_then(|l| l.parse::<u32>().ok());
+                let hits = fields.next().and_then(|h| h.parse::<u64>().ok());
+                if let (Some(line), Some(hits)) = (line, hits) {
+                    let file = self
+                        .files
+                        .entry(path.clone())
+                        .or_insert_with(|| FileCoverage {
+                            canonical: path.canonicalize().ok(),
+                            lines: BTreeMap::new(),
+                        });
+                    *file.lines.entry(line).or_default() += hits;
+                }
+            }
+        }
+    }
+
+    /// Read and merge LCOV files, returning the paths that could not be read
+    pub fn from_files(paths: &[String]) -> (Self, Vec<String>) {
+        let mut coverage = Self::default();
+        let mut unreadable = Vec::new();
+        for path in paths {
+            match fs::read_to_string(path) {
+                Ok(text) => coverage.add_lcov(&text),
+                Err(e) => unreadable.push(format!("{path}: {e}")),
+            }
+        }
+        (coverage, unreadable)
+    }
+
+    /// Line counts for a target, matching LCOV paths that are absolute or
+    /// relative to a different root
+    fn lines_for(&self, target: &Path) -> Option<&BTreeMap<u32, u64>> {
+        let target = normalize(target);
+        let canonical = target.canonicalize().ok();
+        self.files
+            .iter()
+            .find(|(path, file)| {
+                **path == target
+                    || (canonical.is_some() && file.canonical == canonical)
+                    || (path.is_absolute() && path.ends_with(&target))
+                    || (target.is_absolute() && target.ends_with(path))
+            })
+            .map(|(_, file)| &file.lines)
+    }
+
+    /// Whether lines `start..=end` (1-based) of a target are uncovered: at
+    /// least one of them is instrumented and none was executed. Returns `false`
+    /// for files without coverage data.
+    pub fn is_uncovered(&self, target: &Path, start: u32, end: u32) -> bool {
+        self.lines_for(target)
+            .is_some_and(|lines| unexecuted(lines, start, end))
+    }
+}
+
+fn unexecuted(lines: &BTreeMap<u32, u64>, start: u32, end: u32) -> bool {
+    let mut instrumented = lines.range(start..=end).peekable();
+    instrumented.peek().is_some() && instrumented.all(|(_, &hits)| hits == 0)
+}
+
+/// Give every untested mutant of the selection on uncovered lines a `Skipped`
+/// outcome, so test runs pass over it. Returns the number of mutants marked.
+pub async fn mark_uncovered(
+    store: &SqlStore,
+    coverage: &LineCoverage,
+    selection: &Selection,
+) -> StoreResult<usize> {
+    let mut marked = 0;
+    for (target, mutants) in untested_mutants(store, selection).await? {
+        let Some(lines) = coverage.lines_for(&target.path) else {
+            continue;
+        };
+        for mutant in mutants {
+            let (start, end) = mutant.get_lines();
+            if !unexecuted(lines, start, end) {
+                continue;
+            }
+            store
+                .add_outcome(Outcome {
+                    mutant_id: mutant.id,
+                    status: Status::Skipped,
+                    output: format!("{NOT_COVERED}: no test executes lines {start}-{end}"),
+                    time: Utc::now(),
+                    duration_ms: 0,
+                })
+                .await?;
+            marked += 1;
+        }
+    }
+    Ok(marked)
+}
+
+/// Whether an outcome records a mutant skipped for lack of coverage
+pub fn is_not_covered(outcome: &Outcome) -> bool {
+    NOT_COVERED_SKIP.matches(outcome)
+}
+
+/// Mutants skipped for lack of coverage, grouped by target. Other skipped
+/// mutants, such as those the runner skips after an uncaught mutant of higher
+/// severity on the same line, are left out.
+pub async fn uncovered_mutants(store: &SqlStore) -> StoreResult<Vec<(Target, Vec<Mutant>)>> {
+    NOT_COVERED_SKIP.mutants(store).await
+}
+
+/// Log a "Not Covered" section after the output of `muton status` or
+/// `muton results`: how many skipped mutants of each target no test executes,
+/// and with `list` the mutants themselves. Returns the number of mutants.
+pub async fn report_uncovered(store: &SqlStore, list: bool) -> StoreResult<usize> {
+    NOT_COVERED_SKIP.report(store, list).await
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    path.strip_prefix("./").unwrap_or(path).to_path_buf()
+}
@@ -0,0 +1,160 @@
+//! The `muton effectiveness` report: which tests killed each mutant, and how
+//! many mutants each test kills.
+
+use std::collections::BTreeMap;
+
+use mewt::SqlStore;
+use mewt::types::{Status, StoreResult};
+use serde::Serialize;
+
+use crate::test_output::{TestCase, parse_test_output};
+
+/// The tests that failed against one mutant
+#[derive(Debug, Clone, Serialize)]
+pub struct MutantTests {
+    pub mutant_id: i64,
+    pub target: String,
+    pub line: u32,
+    pub slug: String,
+    pub status: String,
+    /// Names of the tests that failed with this mutant applied
+    pub killed_by: Vec<String>,
+    /// First failure message reported against this mutant
+    pub message: Option<String>,
+    #[serde(skip)]
+    cases: Vec<TestCase>,
+}
+
+impl MutantTests {
+    /// Parse a mutant's test output. Returns `None` if the output is not in a
+    /// recognized format.
+    pub fn from_output(
+        mutant_id: i64,
+        target: String,
+        line: u32,
+        slug: String,
+        status: String,
+        output: &str,
+    ) -> Option<Self> {
+        let cases = parse_test_output(output)?;
+        let failed = cases.iter().filter(|c| !c.passed);
+        Some(Self {
+            mutant_id,
+            target,
+            line,
+            slug,
+            status,
+            killed_by: failed.clone().map(|c| c.name.clone()).collect(),
+            message: failed.filter_map(|c| c.message.clone()).next(),
+            cases,
+        })
+    }
+}
+
+/// How many mutants a test killed, and how many of them no other test killed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TestKills {
+    pub name: String,
+    pub kills: usize,
+    pub unique_kills: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EffectivenessReport {
+    pub mutants: Vec<MutantTests>,
+    /// Every test seen in any output, most effective first
+    pub tests: Vec<TestKills>,
+}
+
+impl EffectivenessReport {
+    /// Build the report from every tested mutant in the database
+    pub async fn load(store: &SqlStore) -> StoreResult<Self> {
+        let mut mutants = Vec::new();
+        for target in store.get_all_targets().await? {
+            let by_id: BTreeMap<i64, _> = store
+                .get_mutants(target.id)
+                .await?
+                .into_iter()
+                .map(|m| (m.id, m))
+                .collect();
+            for outcome in store.get_outcomes(target.id).await? {
+                let Some(mutant) = by_id.get(&outcome.mutant_id) else {
+                    continue;
+                };
+                if outcome.status == Status::Skipped {
+                    continue;
+                }
+                mutants.extend(MutantTests::from_output(
+                    mutant.id,
+                    target.display(),
+                    mutant.get_lines().0,
+                    mutant.mutation_slug.clone(),
+                    outcome.status.to_string(),
+                    &outcome.output,
+                ));
+            }
+        }
+        Ok(Self::new(mutants))
+    }
+
+    pub fn new(mutants: Vec<MutantTests>) -> Self {
+        let mut tests: BTreeMap<&str, TestKills> = BTreeMap::new();
+        for mutant in &mutants {
+            for case in &mutant.cases {
+                let entry = tests.entry(&case.name).or_insert_with(|| TestKills {
+                    name: case.name.clone(),
+                    kills: 0,
+                    unique_kills: 0,
+                });
+                if !case.passed {
+                    entry.kills += 1;
+                    if mutant.killed_by.len() == 1 {
+                        entry.unique_kills += 1;
+                    }
+                }
+            }
+        }
+        let mut tests: Vec<TestKills> = tests.into_values().collect();
+        tests.sort_by(|a, b| b.kills.cmp(&a.kills).then_with(|| a.name.cmp(&b.name)));
+        Self { mutants, tests }
+    }
+
+    /// Render the per-test table, flagging tests that never kill a mutant and
+    /// tests whose kills are all shared with other tests
+    pub fn render_table(&self) -> String {
+        let width = self
+            .tests
+            .iter()
+            .map(|t| t.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("Test".len());
+        let mut out = format!(
+            "{:<width$}  {:>5}  {:>6}  Note\n",
+            "Test", "Kills", "Unique"
+        );
+        for test in &self.tests {
+            let note = if test.kills == 0 {
+                "never fails"
+            } else if test.unique_kills == 0 {
+                "redundant"
+            } else {
+                ""
+            };
+            out.push_str(
+                format!(
+                    "{:<width$}  {:>5}  {:>6}  {note}",
+                    test.name, test.kills, test.unique_kills
+                )
+                .trim_end(),
+            );
+            out.push('\n');
+        }
+        out.push_str(&format!(
+            "\n{} mutant(s) with parsed test output, {} test(s)\n",
+            self.mutants.len(),
+            self.tests.len()
+        ));
+        out
+    }
+}
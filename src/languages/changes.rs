@@ -0,0 +1,131 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::process::Command;
+
+use log::{debug, error};
+use mewt::types::{Mutant, Target};
+
+/// Lines of a file that differ from a git revision
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangedLines {
+    /// The file is new or untracked, so every line counts as changed
+    All,
+    /// 1-based line numbers of added or modified lines, plus the lines around
+    /// each deletion
+    Lines(BTreeSet<u32>),
+}
+
+impl ChangedLines {
+    /// Whether any line in `start..=end` (1-based) changed
+    pub fn intersects(&self, start: u32, end: u32) -> bool {
+        match self {
+            ChangedLines::All => true,
+            ChangedLines::Lines(lines) => lines.range(start..=end).next().is_some(),
+        }
+    }
+}
+
+/// Collect the new-side line numbers from the hunk headers of a
+/// `git diff --unified=0` patch
+pub fn parse_unified_diff(diff: &str) -> BTreeSet<u32> {
+    let mut lines = BTreeSet::new();
+    for header in diff.lines().filter(|l| l.starts_with("@@ ")) {
+        let Some(new_range) = header.split(' ').find_map(|part| part.strip_prefix('+')) else {
+            continue;
+        };
+        let mut parts = new_range.split(',');
+        let start = parts.next().and_then(|s| s.parse::<u32>().ok());
+        let count = parts.next().map_or(Some(1), |c| c.parse::<u32>().ok());
+        match (start, count) {
+            // A pure deletion sits between `start` and the line after it
+            (Some(start), Some(0)) => lines.extend([start.max(1), start + 1]),
+            (Some(start), Some(count)) => lines.extend(start..start + count),
+            _ => {}
+        }
+    }
+    lines
+}
+
+/// Diff a file in the working tree against `rev` with the local `git`
+pub fn changed_lines(path: &Path, rev: &str) -> Result<ChangedLines, String> {
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let file = path.file_name().ok_or("not a file")?;
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .arg("--")
+            .arg(file)
+            .output()
+            .map_err(|e| format!("cannot run git: {e}"))
+    };
+
+    let output = git(&["diff", "--unified=0", "--no-color", "--no-ext-diff", rev])?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr
+            .lines()
+            .next()
+            .unwrap_or("git diff failed")
+            .to_string());
+    }
+    if !git(&["ls-files", "--error-unmatch"])?.status.success() {
+        return Ok(ChangedLines::All);
+    }
+    Ok(ChangedLines::Lines(parse_unified_diff(
+        &String::from_utf8_lossy(&output.stdout),
+    )))
+}
+
+/// Diff every target against `rev` before mutants are generated, so that a bad
+/// revision or a missing `git` fails the command
+pub fn check_since(targets: &[Target], rev: &str) -> Result<(), String> {
+    for target in targets {
+        changed_lines(&target.path, rev).map_err(|e| diff_error(target, rev, &e))?;
+    }
+    Ok(())
+}
+
+fn diff_error(target: &Target, rev: &str, error: &str) -> String {
+    format!(
+        "{}: cannot diff against {rev}: {error}",
+        target.path.display()
+    )
+}
+
+/// Keep only the mutants whose lines intersect lines changed since `since`; no
+/// revision keeps every mutant. A target that cannot be diffed keeps none,
+/// though [`check_since`] normally fails the command before that.
+pub fn filter_to_changed_lines(
+    target: &Target,
+    mutants: Vec<Mutant>,
+    since: Option<&str>,
+) -> Vec<Mutant> {
+    let Some(rev) = since else {
+        return mutants;
+    };
+    let changed = match changed_lines(&target.path, rev) {
+        Ok(changed) => changed,
+        Err(e) => {
+            error!("{}", diff_error(target, rev, &e));
+            return Vec::new();
+        }
+    };
+    let kept: Vec<Mutant> = mutants
+        .into_iter()
+        .filter(|m| {
+            let (start, end) = m.get_lines();
+            changed.intersects(start, end)
+        })
+        .collect();
+    debug!(
+        "{}: {} mutant(s) on lines changed since {rev}",
+        target.path.display(),
+        kept.len()
+    );
+    kept
+}
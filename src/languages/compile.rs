@@ -0,0 +1,292 @@
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use log::warn;
+use mewt::SqlStore;
+use mewt::types::{Hash, Outcome, Status, StoreResult, Target};
+
+use crate::config::CompilerConfig;
+use crate::languages::scope::Selection;
+use crate::languages::{SlugCounts, report_slug_counts};
+use crate::skipped::{SkipReason, untested_mutants};
+
+/// Prefix of the output stored with mutants the compiler rejects
+pub const UNCOMPILABLE: &str = "Uncompilable";
+/// Prefix of the output stored with mutants that compile to the original code
+pub const EQUIVALENT: &str = "Equivalent";
+
+/// Mutants the compiler rejects
+pub const UNCOMPILABLE_SKIP: SkipReason = SkipReason {
+    prefix: UNCOMPILABLE,
+    title: "Uncompilable",
+    summary: "do not compile",
+};
+
+/// Mutants that compile to the original code
+pub const EQUIVALENT_SKIP: SkipReason = SkipReason {
+    prefix: EQUIVALENT,
+    title: "Equivalent",
+    summary: "compile to the original code",
+};
+
+/// First bytes of a serialized bag of cells, as written by TON compilers
+const BOC_MAGIC: [u8; 4] = [0xb5, 0xee, 0x9c, 0x72];
+/// A bag of cells printed as hex
+const HEX_BOC_PREFIX: &str = "b5ee9c72";
+/// A bag of cells printed as base64
+const BASE64_BOC_PREFIX: &str = "te6cc";
+
+/// How often a running compiler is checked for completion
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Run a compile command template against `source` and return what it
+/// produced. The source is written to a hidden file next to the target so
+/// relative imports still resolve, and `{file}` in the template is replaced
+/// with its quoted path. If the template has an `{output}` placeholder, the
+/// contents of that file are returned, otherwise the command's stdout. A run
+/// that takes longer than `timeout` is killed and counts as failed.
+pub fn compile(
+    target: &Target,
+    command: &str,
+    source: &str,
+    timeout: Duration,
+) -> Result<Vec<u8>, String> {
+    let path = scratch_path(&target.path, "");
+    let output_path = scratch_path(&target.path, ".out");
+    let stdout_path = scratch_path(&target.path, ".stdout");
+    let stderr_path = scratch_path(&target.path, ".stderr");
+    fs::write(&path, source).map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+    let writes_output = command.contains("{output}");
+    let command = command
+        .replace("{file}", &shell_quote(&path.to_string_lossy()))
+        .replace("{output}", &shell_quote(&output_path.to_string_lossy()));
+    let status = run_shell(&command, &stdout_path, &stderr_path, timeout);
+    let compiled = fs::read(&output_path);
+    let stdout = fs::read(&stdout_path).unwrap_or_default();
+    let stderr = fs::read(&stderr_path).unwrap_or_default();
+    for scratch in [&path, &output_path, &stdout_path, &stderr_path] {
+        let _ = fs::remove_file(scratch);
+    }
+
+    if !status?.success() {
+        let stderr = String::from_utf8_lossy(&stderr);
+        return Err(stderr
+            .lines()
+            .next()
+            .unwrap_or("compilation failed")
+            .to_string());
+    }
+    if writes_output {
+        compiled.map_err(|e| format!("cannot read {}: {e}", output_path.display()))
+    } else {
+        Ok(stdout)
+    }
+}
+
+/// Run a shell command with its output redirected to files. The command gets
+/// its own process group, so a compiler that outlives `timeout` is killed
+/// together with anything it started.
+fn run_shell(
+    command: &str,
+    stdout: &Path,
+    stderr: &Path,
+    timeout: Duration,
+) -> Result<ExitStatus, String> {
+    let create = |path: &Path| {
+        File::create(path).map_err(|e| format!("cannot write {}: {e}", path.display()))
+    };
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::null())
+        .stdout(create(stdout)?)
+        .stderr(create(stderr)?)
+        .process_group(0)
+        .spawn()
+        .map_err(|e| format!("cannot run `{command}`: {e}"))?;
+    let start = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return Ok(status),
+            Ok(None) if start.elapsed() < timeout => thread::sleep(POLL_INTERVAL),
+            Ok(None) => {
+                // Safety: killpg only sends a signal, to the group the child leads
+                unsafe { libc::killpg(child.id() as libc::pid_t, libc::SIGKILL) };
+                let _ = child.wait();
+                return Err(format!("timed out after {}s", timeout.as_secs_f32()));
+            }
+            Err(e) => return Err(format!("cannot wait for `{command}`: {e}")),
+        }
+    }
+}
+
+/// Check that `source` compiles with a compile command template
+pub fn check_compiles(
+    target: &Target,
+    command: &str,
+    source: &str,
+    timeout: Duration,
+) -> Result<(), String> {
+    compile(target, command, source, timeout).map(|_| ())
+}
+
+/// Whether an outcome records a mutant the compiler rejected
+pub fn is_uncompilable(outcome: &Outcome) -> bool {
+    UNCOMPILABLE_SKIP.matches(outcome)
+}
+
+/// Whether an outcome records a mutant that compiles to the original code
+pub fn is_equivalent(outcome: &Outcome) -> bool {
+    EQUIVALENT_SKIP.matches(outcome)
+}
+
+/// Mutants marked by [`mark_compile_outcomes`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompileMarks {
+    pub uncompilable: usize,
+    pub equivalent: usize,
+}
+
+/// Compile every untested mutant of the selection once and record the ones no test needs to run
+/// on as `Skipped`: "Uncompilable" if the compiler rejects them, "Equivalent"
+/// if their compiled code cell matches the original's. A language's
+/// `[equivalence]` command serves both checks, and a `[compile]` command set
+/// for the same language is not run, with a warning; without one, the
+/// `[compile]` command only checks that mutants compile. Targets whose original
+/// does not compile are left alone, since every mutant would then fail the same
+/// way.
+pub async fn mark_compile_outcomes(
+    store: &SqlStore,
+    compile_commands: &CompilerConfig,
+    equivalence_commands: &CompilerConfig,
+    selection: &Selection,
+) -> StoreResult<CompileMarks> {
+    let mut marks = CompileMarks::default();
+    let mut overridden = BTreeSet::new();
+    for (target, mutants) in untested_mutants(store, selection).await? {
+        let (command, timeout, compares) = match (
+            equivalence_commands.command(&target.language),
+            compile_commands.command(&target.language),
+        ) {
+            (Some(command), compile) => {
+                if compile.is_some() && overridden.insert(target.language.clone()) {
+                    warn!(
+                        "Not running the [compile] command for {}: its [equivalence] command also checks that mutants compile",
+                        target.language
+                    );
+                }
+                (command, equivalence_commands.timeout(), true)
+            }
+            (None, Some(command)) => (command, compile_commands.timeout(), false),
+            (None, None) => continue,
+        };
+        let original = match compile(&target, command, &target.text, timeout) {
+            Ok(output) => output,
+            Err(e) => {
+                warn!(
+                    "{}: skipping compile checks because the original does not compile: {e}",
+                    target.path.display()
+                );
+                continue;
+            }
+        };
+        let original_code = if compares {
+            let code = code_cell(&original).map(Hash::digest);
+            if code.is_none() {
+                warn!(
+                    "{}: skipping equivalence check because the compiler output holds no code cell",
+                    target.path.display()
+                );
+            }
+            code
+        } else {
+            None
+        };
+
+        let mut uncompilable = SlugCounts::new();
+        let mut equivalent = SlugCounts::new();
+        for mutant in mutants {
+            let compiled = target
+                .mutate(&mutant)
+                .map_err(|e| e.to_string())
+                .and_then(|mutated| compile(&target, command, &mutated, timeout));
+            let (output, counts) = match compiled {
+                Err(e) => (format!("{UNCOMPILABLE}: {e}"), &mut uncompilable),
+                Ok(code)
+                    if original_code.is_some()
+                        && code_cell(&code).map(Hash::digest) == original_code =>
+                {
+                    (
+                        format!("{EQUIVALENT}: compiled code matches the original"),
+                        &mut equivalent,
+                    )
+                }
+                Ok(_) => continue,
+            };
+            store
+                .add_outcome(Outcome {
+                    mutant_id: mutant.id,
+                    status: Status::Skipped,
+                    output,
+                    time: Utc::now(),
+                    duration_ms: 0,
+                })
+                .await?;
+            *counts.entry(mutant.mutation_slug).or_default() += 1;
+        }
+        report_slug_counts(&target, &uncompilable, "marked", "uncompilable");
+        report_slug_counts(&target, &equivalent, "marked", "equivalent");
+        marks.uncompilable += uncompilable.values().sum::<usize>();
+        marks.equivalent += equivalent.values().sum::<usize>();
+    }
+    Ok(marks)
+}
+
+/// Find the compiled code cell in compiler output, so that debug info, paths
+/// and timestamps printed around it do not tell equivalent mutants apart. The
+/// output is either a binary BoC, or text holding the BoC in hex (starting
+/// `b5ee9c72`) or base64 (starting `te6cc`).
+pub fn code_cell(output: &[u8]) -> Option<String> {
+    if output.starts_with(&BOC_MAGIC) {
+        return Some(hex_encode(output));
+    }
+    let text = String::from_utf8_lossy(output);
+    if let Some(start) = text.to_ascii_lowercase().find(HEX_BOC_PREFIX) {
+        let run: String = text[start..]
+            .chars()
+            .take_while(char::is_ascii_hexdigit)
+            .collect();
+        return Some(run.to_ascii_lowercase());
+    }
+    let start = text.find(BASE64_BOC_PREFIX)?;
+    Some(
+        text[start..]
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '='))
+            .collect(),
+    )
+}
+
+/// Hidden sibling of the target, e.g. `.wallet.muton.fc`, with `suffix` appended
+fn scratch_path(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(ext) => format!(".{stem}.muton.{}{suffix}", ext.to_string_lossy()),
+        None => format!(".{stem}.muton{suffix}"),
+    };
+    path.with_file_name(name)
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
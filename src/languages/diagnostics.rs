@@ -0,0 +1,157 @@
+use std::ops::Range;
+
+use log::{error, warn};
+use mewt::types::Target;
+use mewt::utils::parse_source;
+use tree_sitter::Node;
+
+use crate::languages;
+
+/// Longest snippet of source quoted in a syntax error message
+const MAX_SNIPPET_LEN: usize = 40;
+
+/// A syntax error found in a parsed target, with a 1-based position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+/// Collect the `ERROR` and `MISSING` nodes of a tree, outermost first
+pub fn parse_errors(root: Node, source: &str) -> Vec<ParseError> {
+    error_nodes(root)
+        .into_iter()
+        .map(|node| {
+            let position = node.start_position();
+            let message = if node.is_missing() {
+                format!("missing `{}`", node.kind())
+            } else {
+                format!(
+                    "syntax error near `{}`",
+                    snippet(&source[node.byte_range()])
+                )
+            };
+            ParseError {
+                line: position.row + 1,
+                column: position.column + 1,
+                message,
+            }
+        })
+        .collect()
+}
+
+/// Byte ranges of the `ERROR` and `MISSING` nodes of a tree, outermost first
+pub fn error_ranges(root: Node) -> Vec<Range<usize>> {
+    error_nodes(root)
+        .into_iter()
+        .map(|node| node.byte_range())
+        .collect()
+}
+
+fn error_nodes(root: Node) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    collect_errors(root, &mut nodes);
+    nodes
+}
+
+fn collect_errors<'tree>(node: Node<'tree>, nodes: &mut Vec<Node<'tree>>) {
+    if !node.has_error() {
+        return;
+    }
+    if node.is_missing() || node.is_error() {
+        // Errors nested in an ERROR node add noise without locating anything new
+        nodes.push(node);
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_errors(child, nodes);
+    }
+}
+
+fn snippet(text: &str) -> String {
+    let line = text.lines().next().unwrap_or_default().trim();
+    match line.char_indices().nth(MAX_SNIPPET_LEN) {
+        Some((end, _)) => format!("{}...", &line[..end]),
+        None => line.to_string(),
+    }
+}
+
+/// Log syntax errors for a target as warnings
+pub fn log_parse_errors(target: &Target, errors: &[ParseError]) {
+    if errors.is_empty() {
+        return;
+    }
+    for e in errors {
+        warn!(
+            "{}:{}:{}: {}",
+            target.path.display(),
+            e.line,
+            e.column,
+            e.message
+        );
+    }
+    warn!(
+        "{}: {} parse error(s); mutants may be missing for the affected code",
+        target.path.display(),
+        errors.len()
+    );
+}
+
+/// Log syntax errors for a target as errors, for strict parse mode, where the
+/// run should stop, so a failure message is returned
+pub fn report_parse_errors(target: &Target, errors: &[ParseError]) -> Result<(), String> {
+    if errors.is_empty() {
+        return Ok(());
+    }
+    for e in errors {
+        error!(
+            "{}:{}:{}: {}",
+            target.path.display(),
+            e.line,
+            e.column,
+            e.message
+        );
+    }
+    Err(format!(
+        "{} parse error(s) in {} (strict parse mode)",
+        errors.len(),
+        target.path.display()
+    ))
+}
+
+/// The error reported for a target that could not be parsed at all
+pub fn parse_failure(target: &Target) -> ParseError {
+    ParseError {
+        line: 1,
+        column: 1,
+        message: format!("failed to parse {} source", target.language),
+    }
+}
+
+/// Parse a target with the grammar of its language and collect its syntax
+/// errors. Targets in languages without a grammar have none.
+pub fn target_parse_errors(target: &Target) -> Vec<ParseError> {
+    let Some(language) = languages::grammar(&target.language) else {
+        return Vec::new();
+    };
+    match parse_source(&target.text, language) {
+        Some(tree) => parse_errors(tree.root_node(), &target.text),
+        None => vec![parse_failure(target)],
+    }
+}
+
+/// Fail if any target has syntax errors, for strict parse mode. This runs
+/// before mutants are generated, since an engine has no way to stop a run.
+pub fn check_strict(targets: &[Target]) -> Result<(), String> {
+    let mut failures: Vec<String> = targets
+        .iter()
+        .filter_map(|target| report_parse_errors(target, &target_parse_errors(target)).err())
+        .collect();
+    match failures.len() {
+        0 => Ok(()),
+        1 => Err(failures.remove(0)),
+        n => Err(format!("parse errors in {n} targets (strict parse mode)")),
+    }
+}
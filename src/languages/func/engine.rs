@@ -7,8 +7,13 @@ use mewt::types::{Mutant, Mutation, Target};
 use mewt::utils::{node_text, parse_source};
 use tree_sitter::Language as TsLanguage;
 
+use crate::config::{RuleConfig, muton_config};
 use crate::languages::func::kinds::FUNC_MUTATIONS;
+use crate::languages::func::patterns as func_patterns;
 use crate::languages::func::syntax::{fields, nodes};
+use crate::languages::rules::{self, QueryRule};
+use crate::languages::scope::Selection;
+use crate::languages::{self, diagnostics};
 
 static FUNC_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
 
@@ -17,7 +22,10 @@ unsafe extern "C" {
 }
 
 pub struct FuncLanguageEngine {
-    mutations: Vec<Mutation>,
+    builtin: Vec<Mutation>,
+    rules: OnceLock<Vec<QueryRule>>,
+    mutations: OnceLock<Vec<Mutation>>,
+    selection: Selection,
 }
 
 impl Default for FuncLanguageEngine {
@@ -28,38 +36,85 @@ impl Default for FuncLanguageEngine {
 
 impl FuncLanguageEngine {
     pub fn new() -> Self {
-        let mut mutations: Vec<Mutation> = Vec::new();
-        mutations.extend_from_slice(COMMON_MUTATIONS);
-        mutations.extend_from_slice(FUNC_MUTATIONS);
-        Self { mutations }
+        let mut builtin: Vec<Mutation> = Vec::new();
+        builtin.extend_from_slice(COMMON_MUTATIONS);
+        builtin.extend_from_slice(FUNC_MUTATIONS);
+        Self {
+            builtin,
+            rules: OnceLock::new(),
+            mutations: OnceLock::new(),
+            selection: Selection::default(),
+        }
+    }
+
+    /// Generate only the mutants inside a selection of functions and lines
+    pub fn with_selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Create an engine with the given user-defined rules instead of the ones
+    /// configured in `muton.toml`
+    pub fn with_rules(rules: &[RuleConfig]) -> Self {
+        let engine = Self::new();
+        let compiled = rules::compile_rules(engine.name(), func_language(), &engine.builtin, rules);
+        let _ = engine.rules.set(compiled);
+        engine
+    }
+
+    /// User-defined rules, loaded on first use so that creating an engine does
+    /// not read the config file
+    fn rules(&self) -> &[QueryRule] {
+        self.rules.get_or_init(|| {
+            rules::compile_rules(
+                self.name(),
+                func_language(),
+                &self.builtin,
+                &muton_config().rules(),
+            )
+        })
     }
 }
 
+pub(crate) fn func_language() -> &'static TsLanguage {
+    FUNC_LANGUAGE.get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_func()) })
+}
+
 impl LanguageEngine for FuncLanguageEngine {
     fn name(&self) -> &'static str {
         "FunC"
     }
 
     fn extensions(&self) -> &[&'static str] {
-        &["fc"]
+        &["fc", "func"]
     }
 
     fn get_mutations(&self) -> &[Mutation] {
-        &self.mutations
+        self.mutations.get_or_init(|| {
+            let mut mutations = self.builtin.clone();
+            mutations.extend(self.rules().iter().map(|rule| rule.mutation.clone()));
+            mutations
+        })
     }
 
     fn mutate(&self, target: &Target) -> Vec<Mutant> {
         let source = &target.text;
-        let language =
-            FUNC_LANGUAGE.get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_func()) });
+        let language = func_language();
+        // Strict parse mode is enforced before generation starts, so syntax
+        // errors are only logged here
         let tree = match parse_source(source, language) {
             Some(t) => t,
-            None => return Vec::new(),
+            None => {
+                diagnostics::log_parse_errors(target, &[diagnostics::parse_failure(target)]);
+                return Vec::new();
+            }
         };
         let root = tree.root_node();
+        let errors = diagnostics::parse_errors(root, source);
+        diagnostics::log_parse_errors(target, &errors);
 
         let mut all_mutants = Vec::new();
-        for m in &self.mutations {
+        for m in self.get_mutations() {
             match m.slug {
                 "ER" => {
                     all_mutants.extend(
@@ -307,6 +362,91 @@ impl LanguageEngine for FuncLanguageEngine {
                         .map(|p| Mutant::from_partial(p, target, "SC")),
                     );
                 }
+                "SMF" => all_mutants.extend(
+                    func_patterns::flip_send_mode_bits(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "SMF")),
+                ),
+                "SMB" => all_mutants.extend(
+                    func_patterns::swap_send_mode_base(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "SMB")),
+                ),
+                "AMR" => all_mutants.extend(
+                    func_patterns::remove_call_statements(root, source, "accept_message")
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "AMR")),
+                ),
+                "SDR" => all_mutants.extend(
+                    func_patterns::remove_call_statements(root, source, "set_data")
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "SDR")),
+                ),
+                "CMR" => all_mutants.extend(
+                    func_patterns::remove_call_statements(root, source, "commit")
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "CMR")),
+                ),
+                "RRR" => all_mutants.extend(
+                    func_patterns::remove_call_statements(root, source, "raw_reserve")
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "RRR")),
+                ),
+                "IR" => all_mutants.extend(
+                    func_patterns::remove_impure(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "IR")),
+                ),
+                "MIR" => all_mutants.extend(
+                    func_patterns::remove_method_ids(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "MIR")),
+                ),
+                "MIC" => all_mutants.extend(
+                    func_patterns::change_method_ids(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "MIC")),
+                ),
+                "BER" => all_mutants.extend(
+                    func_patterns::remove_bounced_returns(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "BER")),
+                ),
+                "OPR" => all_mutants.extend(
+                    func_patterns::replace_op_constants(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "OPR")),
+                ),
+                "TD" => all_mutants.extend(
+                    func_patterns::remove_conditional_throws(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "TD")),
+                ),
+                "TS" => all_mutants.extend(
+                    func_patterns::swap_conditional_throws(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "TS")),
+                ),
+                "TN" => all_mutants.extend(
+                    func_patterns::never_throw_conditions(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "TN")),
+                ),
+                "TC" => all_mutants.extend(
+                    func_patterns::change_throw_codes(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "TC")),
+                ),
+                "BW" => all_mutants.extend(
+                    func_patterns::change_bit_widths(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "BW")),
+                ),
+                "LIS" => all_mutants.extend(
+                    func_patterns::swap_load_sign(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "LIS")),
+                ),
                 "LC" => all_mutants.extend(
                     patterns::shuffle_nodes(
                         root,
@@ -458,16 +598,26 @@ impl LanguageEngine for FuncLanguageEngine {
                     .into_iter()
                     .map(|p| Mutant::from_partial(p, target, "NR")),
                 ),
-                _ => {
-                    panic!(
-                        "Unknown mutation slug encountered in FunC engine: {}",
-                        m.slug
-                    );
-                }
+                slug => match self.rules().iter().find(|rule| rule.mutation.slug == slug) {
+                    Some(rule) => all_mutants.extend(
+                        rule.apply(root, source)
+                            .into_iter()
+                            .map(|p| Mutant::from_partial(p, target, slug)),
+                    ),
+                    None => panic!("Unknown mutation slug encountered in FunC engine: {slug}"),
+                },
             }
         }
 
-        all_mutants
+        let functions = func_patterns::function_spans(root, source);
+        languages::finish_mutants(
+            target,
+            language,
+            root,
+            &functions,
+            &self.selection,
+            all_mutants,
+        )
     }
 }
 
@@ -36,6 +36,91 @@ pub const FUNC_MUTATIONS: &[Mutation] = &[
         description: "Store Coins: replace stored coins with zero",
         severity: MutationSeverity::Low,
     },
+    Mutation {
+        slug: "SMF",
+        description: "Send Mode Flag: toggle a flag bit (+1, +2, +16, +32) of a send_raw_message mode",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "SMB",
+        description: "Send Mode Base: swap a send_raw_message base mode between 0, 64 and 128",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "TD",
+        description: "Throw Deletion: remove a throw_if/throw_unless statement",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "TS",
+        description: "Throw Swap: swap throw_if with throw_unless",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "TN",
+        description: "Throw Never: replace a throw_if/throw_unless condition with a constant that never throws",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "TC",
+        description: "Throw Code: change the error code of a throw_if/throw_unless",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "AMR",
+        description: "Accept Message Removal: remove an accept_message() call",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "SDR",
+        description: "Set Data Removal: remove a set_data() call that persists storage",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "CMR",
+        description: "Commit Removal: remove a commit() checkpoint",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "RRR",
+        description: "Raw Reserve Removal: remove a raw_reserve() call",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "BW",
+        description: "Bit Width: change the bit width of a store_uint/store_int/load_uint/load_int",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "LIS",
+        description: "Load Int Swap: swap load_uint with load_int",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "IR",
+        description: "Impure Removal: drop the impure specifier so unused calls can be optimized away",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "MIR",
+        description: "Method Id Removal: drop the method_id specifier of a get-method",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "MIC",
+        description: "Method Id Change: change the id in method_id(N) to the next id not declared in the file",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "BER",
+        description: "Bounced Early Return: remove the if (flags & 1) { return (); } guard that ignores bounced messages",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "OPR",
+        description: "Op Replacement: compare the op against a different op constant; only constants named op::x or op_x are recognized",
+        severity: MutationSeverity::High,
+    },
     // FunC-specific operator shuffles (not shared)
     Mutation {
         slug: "DOS",
@@ -0,0 +1,577 @@
+use std::collections::HashSet;
+
+use mewt::types::PartialMutant;
+use mewt::utils::{is_in_comment, node_text, visit_nodes_with_cursor};
+use tree_sitter::Node;
+
+use crate::languages::func::syntax::{fields, nodes};
+use crate::languages::patterns::{increment_operand, parse_int, remove_statement, replace_node};
+use crate::languages::scope::FunctionSpan;
+
+/// A conditional throw as (name, opposite, code argument, condition argument)
+type ConditionalThrow = (&'static str, &'static str, usize, usize);
+
+const CONDITIONAL_THROWS: &[ConditionalThrow] = &[
+    ("throw_if", "throw_unless", 0, 1),
+    ("throw_unless", "throw_if", 0, 1),
+    ("throw_arg_if", "throw_arg_unless", 1, 2),
+    ("throw_arg_unless", "throw_arg_if", 1, 2),
+];
+
+/// Serialization calls with the logical position of their bit width argument
+const SIZED_CALLS: &[(&str, usize)] = &[
+    ("store_uint", 2),
+    ("store_int", 2),
+    ("load_uint", 1),
+    ("load_int", 1),
+    ("preload_uint", 1),
+    ("preload_int", 1),
+];
+/// Bit widths commonly used for serialized fields, smallest first
+const COMMON_WIDTHS: &[u64] = &[8, 16, 32, 64, 128, 256];
+/// Width of a serialized `MsgAddressInt`, which is commonly confused with 256
+const ADDRESS_WIDTH: u64 = 267;
+/// Signedness swaps between the unsigned and signed loaders
+const LOAD_SIGN_SWAPS: &[(&str, &str)] = &[
+    ("load_uint", "load_int"),
+    ("load_int", "load_uint"),
+    ("preload_uint", "preload_int"),
+    ("preload_int", "preload_uint"),
+];
+
+/// Send mode flags that can be toggled independently of the base mode
+const SEND_MODE_FLAGS: &[u64] = &[1, 2, 16, 32];
+/// Mutually exclusive base send modes (ordinary, carry inbound value, carry balance)
+const SEND_MODE_BASES: &[u64] = &[0, 64, 128];
+const SEND_MODE_BASE_MASK: u64 = 64 | 128;
+
+/// A call site normalized across the `f(a, b)` and `a.f(b)` / `a~f(b)` forms
+pub struct Call<'tree> {
+    pub name: &'tree str,
+    /// The identifier naming the called function or method
+    pub name_node: Node<'tree>,
+    /// Explicit arguments; excludes the receiver of a method call
+    pub args: Vec<Node<'tree>>,
+    /// True for `.f()`/`~f()` calls, whose first logical argument is the receiver
+    pub is_method: bool,
+}
+
+impl<'tree> Call<'tree> {
+    /// Get an argument by its logical position, counting a method receiver as argument 0
+    pub fn arg(&self, index: usize) -> Option<Node<'tree>> {
+        if self.is_method {
+            index.checked_sub(1).and_then(|i| self.args.get(i).copied())
+        } else {
+            self.args.get(index).copied()
+        }
+    }
+}
+
+/// Interpret a function application or method call node as a [`Call`]
+pub fn as_call<'tree>(node: Node<'tree>, source: &'tree str) -> Option<Call<'tree>> {
+    let (name_node, is_method) = match node.kind() {
+        nodes::FUNCTION_APPLICATION => (node.child_by_field_name(fields::FUNCTION)?, false),
+        nodes::METHOD_CALL => (node.child_by_field_name(fields::METHOD_NAME)?, true),
+        _ => return None,
+    };
+    let args_node = node
+        .child_by_field_name(fields::ARGUMENTS)
+        .or_else(|| node.child_by_field_name(fields::AGRUMENTS))?;
+    let args = match args_node.kind() {
+        nodes::UNIT_LITERAL => Vec::new(),
+        nodes::TENSOR_EXPRESSION | nodes::PARENTHESIZED_EXPRESSION => {
+            let mut c = args_node.walk();
+            args_node.named_children(&mut c).collect()
+        }
+        _ => vec![args_node],
+    };
+    Some(Call {
+        name: node_text(&name_node, source),
+        name_node,
+        args,
+        is_method,
+    })
+}
+
+/// Visit every call outside of comments
+fn visit_calls(root: Node, source: &str, callback: &mut dyn FnMut(&Call<'_>)) {
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if is_in_comment(&node) {
+            return;
+        }
+        if let Some(call) = as_call(node, source) {
+            callback(&call);
+        }
+    });
+}
+
+/// Toggle individual flag bits of the `send_raw_message` mode argument
+pub fn flip_send_mode_bits(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_calls(root, source, &mut |call| {
+        let Some(mode) = send_mode_arg(call) else {
+            return;
+        };
+        let mode_text = node_text(&mode, source);
+        for flag in SEND_MODE_FLAGS {
+            let new_text = match eval_mode(mode_text) {
+                Some(value) => render_mode(value ^ flag),
+                None if is_simple_operand(mode_text) => format!("{mode_text} ^ {flag}"),
+                None => format!("({mode_text}) ^ {flag}"),
+            };
+            mutants.push(replace_node(&mode, source, new_text));
+        }
+    });
+    mutants
+}
+
+/// Swap the base mode (0, 64, 128) of a literal `send_raw_message` mode argument,
+/// preserving any flag bits
+pub fn swap_send_mode_base(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_calls(root, source, &mut |call| {
+        let Some(mode) = send_mode_arg(call) else {
+            return;
+        };
+        let Some(value) = eval_mode(node_text(&mode, source)) else {
+            return;
+        };
+        let base = value & SEND_MODE_BASE_MASK;
+        for &other in SEND_MODE_BASES.iter().filter(|&&b| b != base) {
+            let new_value = (value & !SEND_MODE_BASE_MASK) | other;
+            mutants.push(replace_node(&mode, source, render_mode(new_value)));
+        }
+    });
+    mutants
+}
+
+/// Visit every `throw_if`/`throw_unless` call (and their `throw_arg_` forms),
+/// with the matching [`CONDITIONAL_THROWS`] entry
+fn visit_conditional_throws(
+    root: Node,
+    source: &str,
+    callback: &mut dyn FnMut(Node<'_>, &Call<'_>, &ConditionalThrow),
+) {
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if is_in_comment(&node) {
+            return;
+        }
+        let Some(call) = as_call(node, source) else {
+            return;
+        };
+        if let Some(entry) = CONDITIONAL_THROWS
+            .iter()
+            .find(|(name, ..)| *name == call.name)
+        {
+            callback(node, &call, entry);
+        }
+    });
+}
+
+/// Find the statement made up by a call whose result is discarded, as in
+/// `f(...);` or `cell.f();` at the end of a method chain
+fn call_statement(node: Node) -> Option<Node> {
+    node.parent()
+        .filter(|p| p.kind() == nodes::EXPRESSION)
+        .filter(|p| match node.kind() {
+            nodes::METHOD_CALL => p.end_byte() == node.end_byte(),
+            _ => p.byte_range() == node.byte_range(),
+        })
+        .and_then(|p| p.parent())
+        .filter(|p| p.kind() == nodes::EXPRESSION_STATEMENT)
+}
+
+/// Delete `throw_if`/`throw_unless` statements
+pub fn remove_conditional_throws(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_conditional_throws(root, source, &mut |node, _, _| {
+        if let Some(statement) = call_statement(node) {
+            mutants.push(replace_node(&statement, source, ""));
+        }
+    });
+    mutants
+}
+
+/// Delete statements that call the named built-in, such as `accept_message();`
+/// or `cell.set_data();`
+pub fn remove_call_statements(root: Node, source: &str, name: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if is_in_comment(&node) || as_call(node, source).is_none_or(|call| call.name != name) {
+            return;
+        }
+        if let Some(statement) = call_statement(node) {
+            mutants.push(replace_node(&statement, source, ""));
+        }
+    });
+    mutants
+}
+
+/// Swap `throw_if` with `throw_unless` and vice versa
+pub fn swap_conditional_throws(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_conditional_throws(root, source, &mut |_, call, (_, opposite, ..)| {
+        mutants.push(replace_node(&call.name_node, source, *opposite));
+    });
+    mutants
+}
+
+/// Replace the condition of `throw_if`/`throw_unless` with a constant that never
+/// throws (`0` for `throw_if`, `-1` for `throw_unless`)
+pub fn never_throw_conditions(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_conditional_throws(root, source, &mut |_, call, (name, _, _, cond_index)| {
+        let Some(condition) = call.arg(*cond_index) else {
+            return;
+        };
+        let never = if name.ends_with("_if") { "0" } else { "-1" };
+        if node_text(&condition, source) != never {
+            mutants.push(replace_node(&condition, source, never));
+        }
+    });
+    mutants
+}
+
+/// Change the error code of `throw_if`/`throw_unless`
+pub fn change_throw_codes(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_conditional_throws(root, source, &mut |_, call, (_, _, code_index, _)| {
+        let Some(code) = call.arg(*code_index) else {
+            return;
+        };
+        let code_text = node_text(&code, source);
+        if let Some(new_text) = increment_operand(code_text, is_simple_operand(code_text)) {
+            mutants.push(replace_node(&code, source, new_text));
+        }
+    });
+    mutants
+}
+
+/// Change the literal bit width of `store_uint`/`store_int`/`load_uint`/`load_int`
+/// to a neighbouring width
+pub fn change_bit_widths(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_calls(root, source, &mut |call| {
+        let Some((_, width_index)) = SIZED_CALLS.iter().find(|(name, _)| *name == call.name) else {
+            return;
+        };
+        let Some(width) = call.arg(*width_index) else {
+            return;
+        };
+        let Some(value) = parse_int(node_text(&width, source)) else {
+            return;
+        };
+        for new_width in neighbouring_widths(value) {
+            mutants.push(replace_node(&width, source, new_width.to_string()));
+        }
+    });
+    mutants
+}
+
+/// Swap `load_uint` with `load_int` and vice versa
+pub fn swap_load_sign(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_calls(root, source, &mut |call| {
+        if let Some((_, swapped)) = LOAD_SIGN_SWAPS.iter().find(|(name, _)| *name == call.name) {
+            mutants.push(replace_node(&call.name_node, source, *swapped));
+        }
+    });
+    mutants
+}
+
+/// Common widths map to the next smaller and larger common widths, an address
+/// width to 256, and anything else to the widths one bit off
+fn neighbouring_widths(width: u64) -> Vec<u64> {
+    if width == ADDRESS_WIDTH {
+        return vec![256];
+    }
+    match COMMON_WIDTHS.iter().position(|&w| w == width) {
+        Some(i) => [i.checked_sub(1), Some(i + 1)]
+            .into_iter()
+            .flatten()
+            .filter_map(|j| COMMON_WIDTHS.get(j).copied())
+            .collect(),
+        None => [width.checked_sub(1), Some(width + 1)]
+            .into_iter()
+            .flatten()
+            .filter(|&w| w > 0)
+            .collect(),
+    }
+}
+
+fn send_mode_arg<'tree>(call: &Call<'tree>) -> Option<Node<'tree>> {
+    if call.name == "send_raw_message" {
+        call.arg(1)
+    } else {
+        None
+    }
+}
+
+/// Evaluate a mode expression made only of integer literals joined by `+` or
+/// `|`. Both share a precedence level in FunC, so they apply left to right.
+fn eval_mode(text: &str) -> Option<u64> {
+    let text = text.trim();
+    let mut rest = text
+        .strip_prefix('(')
+        .and_then(|t| t.strip_suffix(')'))
+        .unwrap_or(text);
+    let mut value = 0u64;
+    let mut op = '+';
+    loop {
+        let end = rest.find(['+', '|']).unwrap_or(rest.len());
+        let term = parse_int(rest[..end].trim())?;
+        value = match op {
+            '|' => value | term,
+            _ => value.checked_add(term)?,
+        };
+        let Some(next) = rest[end..].chars().next() else {
+            return Some(value);
+        };
+        op = next;
+        rest = &rest[end + 1..];
+    }
+}
+
+/// Render a mode as a sum of its set bits, highest first (e.g. `64 + 2 + 1`)
+fn render_mode(value: u64) -> String {
+    if value == 0 {
+        return "0".to_string();
+    }
+    (0..u64::BITS)
+        .rev()
+        .map(|bit| 1u64 << bit)
+        .filter(|bit| value & bit != 0)
+        .map(|bit| bit.to_string())
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+/// FunC requires whitespace around binary operators, so any operand without
+/// whitespace is a single token
+fn is_simple_operand(text: &str) -> bool {
+    !text.contains(char::is_whitespace)
+}
+
+/// Visit each function specifier together with its `specifiers_list`
+fn visit_specifiers(root: Node, kind: &str, callback: &mut dyn FnMut(Node<'_>, Node<'_>)) {
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if node.kind() != nodes::SPECIFIERS_LIST || is_in_comment(&node) {
+            return;
+        }
+        let mut c = node.walk();
+        for specifier in node.named_children(&mut c).filter(|s| s.kind() == kind) {
+            callback(node, specifier);
+        }
+    });
+}
+
+/// Rewrite a `specifiers_list` without one of its specifiers
+fn remove_specifier(list: &Node, specifier: &Node, source: &str) -> PartialMutant {
+    let mut c = list.walk();
+    let rest = list
+        .named_children(&mut c)
+        .filter(|s| s.id() != specifier.id())
+        .map(|s| node_text(&s, source))
+        .collect::<Vec<_>>()
+        .join(" ");
+    replace_node(list, source, rest)
+}
+
+/// Drop the `impure` specifier, which lets the compiler delete calls whose
+/// result is unused
+pub fn remove_impure(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_specifiers(root, nodes::IMPURE, &mut |list, specifier| {
+        mutants.push(remove_specifier(&list, &specifier, source));
+    });
+    mutants
+}
+
+/// Drop the `method_id` specifier of a get-method
+pub fn remove_method_ids(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_specifiers(root, nodes::METHOD_ID, &mut |list, specifier| {
+        mutants.push(remove_specifier(&list, &specifier, source));
+    });
+    mutants
+}
+
+/// Change an explicit `method_id(N)` to the next id above `N` that no other
+/// `method_id` in the file declares
+pub fn change_method_ids(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut declared = HashSet::new();
+    visit_specifiers(root, nodes::METHOD_ID, &mut |_, specifier| {
+        declared.extend(explicit_method_id(&specifier, source).map(|(_, value)| value));
+    });
+    let mut mutants = Vec::new();
+    visit_specifiers(root, nodes::METHOD_ID, &mut |_, specifier| {
+        let Some((id, value)) = explicit_method_id(&specifier, source) else {
+            return;
+        };
+        let mut next = value.checked_add(1);
+        while let Some(taken) = next.filter(|n| declared.contains(n)) {
+            next = taken.checked_add(1);
+        }
+        if let Some(next) = next {
+            mutants.push(replace_node(&id, source, next.to_string()));
+        }
+    });
+    mutants
+}
+
+/// The number literal of a `method_id(N)` specifier and its value
+fn explicit_method_id<'a>(specifier: &Node<'a>, source: &str) -> Option<(Node<'a>, u64)> {
+    let mut c = specifier.walk();
+    let id = specifier
+        .named_children(&mut c)
+        .find(|n| n.kind() == nodes::NUMBER_LITERAL)?;
+    let value = parse_int(node_text(&id, source))?;
+    Some((id, value))
+}
+
+/// Delete the `if (flags & 1) { return (); }` guard that ignores bounced
+/// messages, so bounces are handled like ordinary messages
+pub fn remove_bounced_returns(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if node.kind() != nodes::IF_STATEMENT
+            || is_in_comment(&node)
+            || node.child(0).is_none_or(|k| k.kind() != "if")
+            || node.child_by_field_name(fields::ALTERNATIVE).is_some()
+        {
+            return;
+        }
+        let is_bounce_check = node
+            .child_by_field_name(fields::CONDITION)
+            .and_then(|c| binary_operands(unparenthesize(c), "&"))
+            .is_some_and(|(l, r)| {
+                [l, r]
+                    .iter()
+                    .any(|n| parse_int(node_text(n, source)) == Some(1))
+            });
+        let returns_only = node
+            .child_by_field_name(fields::CONSEQUENT)
+            .is_some_and(|block| is_return_block(&block));
+        if is_bounce_check && returns_only {
+            mutants.push(remove_statement(&node, source));
+        }
+    });
+    mutants
+}
+
+/// Replace the op constant in `op == op::x` and `op != op::x` comparisons with
+/// each other `op::`/`op_` constant declared in the same file
+pub fn replace_op_constants(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut constants: Vec<&str> = Vec::new();
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if node.kind() != nodes::CONSTANT_DECLARATION {
+            return;
+        }
+        if let Some(name) = node.child_by_field_name(fields::NAME) {
+            let name = node_text(&name, source);
+            if is_op_constant(name) && !constants.contains(&name) {
+                constants.push(name);
+            }
+        }
+    });
+    if constants.len() < 2 {
+        return Vec::new();
+    }
+
+    let mut mutants = Vec::new();
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if node.kind() != nodes::EXPRESSION || is_in_comment(&node) {
+            return;
+        }
+        let operands = binary_operands(node, "==").or_else(|| binary_operands(node, "!="));
+        let Some((left, right)) = operands else {
+            return;
+        };
+        for operand in [left, right] {
+            let name = node_text(&operand, source);
+            if operand.kind() != nodes::IDENTIFIER || !constants.contains(&name) {
+                continue;
+            }
+            for other in constants.iter().filter(|&&c| c != name) {
+                mutants.push(replace_node(&operand, source, *other));
+            }
+        }
+    });
+    mutants
+}
+
+/// Op constants are conventionally named `op::name` or `op_name`
+fn is_op_constant(name: &str) -> bool {
+    name.starts_with("op::") || name.starts_with("op_")
+}
+
+/// The operands of a flat `a <op> b` expression
+fn binary_operands<'tree>(node: Node<'tree>, operator: &str) -> Option<(Node<'tree>, Node<'tree>)> {
+    if node.kind() != nodes::EXPRESSION || node.child_count() != 3 {
+        return None;
+    }
+    let (left, op, right) = (node.child(0)?, node.child(1)?, node.child(2)?);
+    (op.kind() == operator).then_some((left, right))
+}
+
+/// Strip the parentheses around an `if` condition
+fn unparenthesize(mut node: Node) -> Node {
+    loop {
+        let mut c = node.walk();
+        let mut children = node.named_children(&mut c);
+        match (children.next(), children.next()) {
+            (Some(inner), None)
+                if matches!(
+                    inner.kind(),
+                    nodes::EXPRESSION | nodes::PARENTHESIZED_EXPRESSION
+                ) =>
+            {
+                node = inner
+            }
+            _ => return node,
+        }
+    }
+}
+
+/// Whether a block holds nothing but a single `return` statement
+fn is_return_block(block: &Node) -> bool {
+    if block.kind() != nodes::BLOCK_STATEMENT {
+        return false;
+    }
+    let mut c = block.walk();
+    let mut statements = block
+        .named_children(&mut c)
+        .filter(|n| n.kind() == nodes::STATEMENT);
+    let only = match (statements.next(), statements.next()) {
+        (Some(only), None) => only,
+        _ => return false,
+    };
+    only.named_child(0)
+        .is_some_and(|n| n.kind() == nodes::RETURN_STATEMENT)
+}
+
+/// Find the span of every function definition, named as in the source
+pub fn function_spans(root: Node, source: &str) -> Vec<FunctionSpan> {
+    let mut spans = Vec::new();
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if node.kind() != nodes::FUNCTION_DEFINITION {
+            return;
+        }
+        if let Some(name) = node.child_by_field_name(fields::NAME) {
+            spans.push(FunctionSpan {
+                name: node_text(&name, source).to_string(),
+                start_byte: node.start_byte(),
+                end_byte: node.end_byte(),
+            });
+        }
+    });
+    spans
+}
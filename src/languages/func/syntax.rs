@@ -4,19 +4,34 @@ pub mod nodes {
     pub const DO_STATEMENT: &str = "do_statement";
     pub const EXPRESSION_STATEMENT: &str = "expression_statement";
     pub const FUNCTION_APPLICATION: &str = "function_application";
+    pub const FUNCTION_DEFINITION: &str = "function_definition";
     pub const IDENTIFIER: &str = "identifier";
     pub const IF_STATEMENT: &str = "if_statement";
     // tree-sitter-func models both `if` and `ifnot` as `if_statement`
     pub const IFNOT_STATEMENT: &str = "if_statement";
     pub const METHOD_CALL: &str = "method_call";
+    pub const PARENTHESIZED_EXPRESSION: &str = "parenthesized_expression";
     pub const REPEAT_STATEMENT: &str = "repeat_statement";
     pub const RETURN_STATEMENT: &str = "return_statement";
+    pub const TENSOR_EXPRESSION: &str = "tensor_expression";
+    pub const UNIT_LITERAL: &str = "unit_literal";
     pub const UNTIL_STATEMENT: &str = "until_statement";
     pub const VARIABLE_DECLARATION: &str = "variable_declaration";
     pub const WHILE_STATEMENT: &str = "while_statement";
     pub const EXPRESSION: &str = "expression";
     pub const BOOLEAN: &str = "boolean";
 
+    // Function specifiers
+    pub const SPECIFIERS_LIST: &str = "specifiers_list";
+    pub const IMPURE: &str = "impure";
+    pub const METHOD_ID: &str = "method_id";
+    pub const NUMBER_LITERAL: &str = "number_literal";
+
+    // Message handling idioms
+    pub const BLOCK_STATEMENT: &str = "block_statement";
+    pub const CONSTANT_DECLARATION: &str = "constant_declaration";
+    pub const STATEMENT: &str = "statement";
+
     // Statements and controls
     pub const BREAK_STATEMENT: &str = "break_statement";
     pub const CONTINUE_STATEMENT: &str = "continue_statement";
@@ -27,8 +42,15 @@ pub mod fields {
     pub const ARGUMENTS: &str = "arguments";
     pub const AGRUMENTS: &str = "agruments";
 
+    // Callee names for function applications and method calls
+    pub const FUNCTION: &str = "function";
+    pub const NAME: &str = "name";
+    pub const METHOD_NAME: &str = "method_name";
+
     // Common field names used by patterns
     pub const CONDITION: &str = "condition";
+    pub const CONSEQUENT: &str = "consequent";
+    pub const ALTERNATIVE: &str = "alternative";
     pub const COUNT: &str = "count";
     pub const POSTCONDITION: &str = "postcondition";
 }
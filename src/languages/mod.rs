@@ -1,3 +1,86 @@
+pub mod changes;
+pub mod compile;
+pub mod diagnostics;
 pub mod func;
+pub mod patterns;
+pub mod rules;
+pub mod scope;
+pub mod suppression;
 pub mod tact;
 pub mod tolk;
+pub mod validation;
+
+use std::collections::BTreeMap;
+
+use log::info;
+use mewt::types::{Mutant, Target};
+use mewt::utils::parse_source;
+use tree_sitter::{Language as TsLanguage, Node};
+
+use crate::languages::scope::{FunctionSpan, Selection};
+
+/// Mutant counts per mutation slug
+pub type SlugCounts = BTreeMap<String, usize>;
+
+/// Log how many of a target's mutants were handled in some way, per slug, as
+/// in `wallet.fc: discarded 3 unparsable mutant(s) (AOS=1, MOS=2)`
+pub fn report_slug_counts(target: &Target, counts: &SlugCounts, action: &str, kind: &str) {
+    if counts.is_empty() {
+        return;
+    }
+    let total: usize = counts.values().sum();
+    let per_slug = counts
+        .iter()
+        .map(|(slug, count)| format!("{slug}={count}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    info!(
+        "{}: {action} {total} {kind} mutant(s) ({per_slug})",
+        target.path.display()
+    );
+}
+
+/// Get the tree-sitter grammar of a language by its engine name
+pub fn grammar(language: &str) -> Option<&'static TsLanguage> {
+    match language {
+        "FunC" => Some(func::engine::func_language()),
+        "Tact" => Some(tact::engine::tact_language()),
+        "Tolk" => Some(tolk::engine::tolk_language()),
+        _ => None,
+    }
+}
+
+/// Get the named functions, receivers and methods declared in a target
+pub fn function_spans(target: &Target) -> Vec<FunctionSpan> {
+    let Some(tree) =
+        grammar(&target.language).and_then(|language| parse_source(&target.text, language))
+    else {
+        return Vec::new();
+    };
+    let root = tree.root_node();
+    match target.language.as_str() {
+        "FunC" => func::patterns::function_spans(root, &target.text),
+        "Tact" => tact::patterns::function_spans(root, &target.text),
+        "Tolk" => tolk::patterns::function_spans(root, &target.text),
+        _ => Vec::new(),
+    }
+}
+
+/// Narrow the mutants an engine generated for a target to the ones it saves:
+/// drop the suppressed ones and those outside the selection, then discard the
+/// ones that no longer parse, logging what is left per function
+pub fn finish_mutants(
+    target: &Target,
+    language: &TsLanguage,
+    root: Node,
+    functions: &[FunctionSpan],
+    selection: &Selection,
+    mutants: Vec<Mutant>,
+) -> Vec<Mutant> {
+    let mutants = suppression::filter_suppressed(target, root, functions, mutants);
+    let mutants = selection.filter(target, functions, mutants);
+    let (mutants, discarded) = validation::discard_invalid_mutants(target, language, root, mutants);
+    report_slug_counts(target, &discarded, "discarded", "unparsable");
+    scope::report_functions(target, functions, &mutants);
+    mutants
+}
@@ -0,0 +1,68 @@
+use mewt::types::PartialMutant;
+use mewt::utils::{calculate_line_offset, node_text};
+use tree_sitter::Node;
+
+/// Replace the full text of a node
+pub fn replace_node(node: &Node, source: &str, new_text: impl Into<String>) -> PartialMutant {
+    PartialMutant {
+        byte_offset: node.start_byte() as u32,
+        line_offset: calculate_line_offset(source, node.start_byte()),
+        old_text: node_text(node, source).to_string(),
+        new_text: new_text.into(),
+    }
+}
+
+/// Remove an item from a comma-separated list, along with the separator that
+/// joins it to its neighbours (the following comma and whitespace, or else the
+/// preceding comma)
+pub fn remove_list_item(node: &Node, source: &str) -> PartialMutant {
+    let (start, end) = match (node.next_sibling(), node.prev_sibling()) {
+        (Some(next), _) if next.kind() == "," => {
+            let rest = &source[next.end_byte()..];
+            let whitespace = rest.len() - rest.trim_start().len();
+            (node.start_byte(), next.end_byte() + whitespace)
+        }
+        (_, Some(prev)) if prev.kind() == "," => (prev.start_byte(), node.end_byte()),
+        _ => (node.start_byte(), node.end_byte()),
+    };
+    PartialMutant {
+        byte_offset: start as u32,
+        line_offset: calculate_line_offset(source, start),
+        old_text: source[start..end].to_string(),
+        new_text: String::new(),
+    }
+}
+
+/// Remove a statement, including a terminating `;` that the grammar keeps as a
+/// sibling rather than as part of the statement node
+pub fn remove_statement(node: &Node, source: &str) -> PartialMutant {
+    let end = match node.next_sibling() {
+        Some(next) if next.kind() == ";" => next.end_byte(),
+        _ => node.end_byte(),
+    };
+    PartialMutant {
+        byte_offset: node.start_byte() as u32,
+        line_offset: calculate_line_offset(source, node.start_byte()),
+        old_text: source[node.start_byte()..end].to_string(),
+        new_text: String::new(),
+    }
+}
+
+/// Parse a decimal or `0x`/`0X`-prefixed hexadecimal integer literal
+pub fn parse_int(text: &str) -> Option<u64> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
+
+/// Add one to an exit code operand. Integer literals are incremented in place,
+/// or `None` is returned if that overflows; any other operand gets `+ 1`,
+/// parenthesized unless it is `simple`.
+pub fn increment_operand(text: &str, simple: bool) -> Option<String> {
+    match parse_int(text) {
+        Some(value) => value.checked_add(1).map(|v| v.to_string()),
+        None if simple => Some(format!("{text} + 1")),
+        None => Some(format!("({text}) + 1")),
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use log::warn;
+use mewt::types::{Mutation, MutationSeverity, PartialMutant};
+use mewt::utils::is_in_comment;
+use tree_sitter::{Language as TsLanguage, Node, Query, QueryCursor, StreamingIterator};
+
+use crate::config::RuleConfig;
+use crate::languages::patterns::replace_node;
+
+/// A user-defined mutation compiled from a [`RuleConfig`]
+pub struct QueryRule {
+    pub mutation: Mutation,
+    query: Query,
+    capture_index: u32,
+    replacement: String,
+}
+
+impl QueryRule {
+    /// Compile a rule's query against a language
+    pub fn compile(rule: &RuleConfig, language: &TsLanguage) -> Result<Self, String> {
+        let severity = rule
+            .severity
+            .parse::<MutationSeverity>()
+            .map_err(|_| format!("unknown severity `{}`", rule.severity))?;
+        let query = Query::new(language, &rule.query).map_err(|e| format!("invalid query: {e}"))?;
+        let capture_index = query
+            .capture_index_for_name(rule.capture())
+            .ok_or_else(|| format!("query has no `@{}` capture", rule.capture()))?;
+        Ok(Self {
+            // Engines live for the whole process, so leaking the few rule strings is fine
+            mutation: Mutation {
+                slug: Box::leak(rule.slug.clone().into_boxed_str()),
+                description: Box::leak(rule.description.clone().into_boxed_str()),
+                severity,
+            },
+            query,
+            capture_index,
+            replacement: rule.replacement.clone(),
+        })
+    }
+
+    /// Replace every captured node with the rendered replacement template
+    pub fn apply(&self, root: Node, source: &str) -> Vec<PartialMutant> {
+        let mut mutants = Vec::new();
+        let mut seen = HashSet::new();
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&self.query, root, source.as_bytes());
+        while let Some(m) = matches.next() {
+            let captures: Vec<(&str, &str)> = m
+                .captures
+                .iter()
+                .map(|c| {
+                    let name = self.query.capture_names()[c.index as usize];
+                    (name, &source[c.node.byte_range()])
+                })
+                .collect();
+            let new_text = render(&self.replacement, &captures);
+            for capture in m.captures.iter().filter(|c| c.index == self.capture_index) {
+                let node = capture.node;
+                if is_in_comment(&node) || source[node.byte_range()] == new_text {
+                    continue;
+                }
+                if seen.insert((node.start_byte(), node.end_byte(), new_text.clone())) {
+                    mutants.push(replace_node(&node, source, new_text.clone()));
+                }
+            }
+        }
+        mutants
+    }
+}
+
+/// Expand each `{name}` in a replacement template to the text of capture
+/// `name`, in a single pass so placeholders inside captured text stay as they
+/// are. Braces that do not name a capture are kept.
+fn render(template: &str, captures: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        rest = &rest[open..];
+        let expansion = rest.find('}').and_then(|close| {
+            let name = &rest[1..close];
+            let (_, text) = captures.iter().find(|(n, _)| *n == name)?;
+            Some((close, *text))
+        });
+        match expansion {
+            Some((close, text)) => {
+                out.push_str(text);
+                rest = &rest[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Compile the rules for one language, skipping (with a warning) rules that do
+/// not compile or whose slug is already taken
+pub fn compile_rules(
+    language_name: &str,
+    language: &TsLanguage,
+    builtin: &[Mutation],
+    rules: &[RuleConfig],
+) -> Vec<QueryRule> {
+    let mut slugs: HashSet<&str> = builtin.iter().map(|m| m.slug).collect();
+    let mut compiled = Vec::new();
+    for rule in rules
+        .iter()
+        .filter(|r| r.language.eq_ignore_ascii_case(language_name))
+    {
+        if slugs.contains(rule.slug.as_str()) {
+            warn!(
+                "Skipping {language_name} rule {}: slug is already in use",
+                rule.slug
+            );
+            continue;
+        }
+        match QueryRule::compile(rule, language) {
+            Ok(query_rule) => {
+                slugs.insert(query_rule.mutation.slug);
+                compiled.push(query_rule);
+            }
+            Err(e) => warn!("Skipping {language_name} rule {}: {e}", rule.slug),
+        }
+    }
+    compiled
+}
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use log::debug;
+use mewt::types::{Mutant, Target};
+
+use crate::languages::changes;
+
+/// A named function, receiver or method and the bytes it covers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionSpan {
+    pub name: String,
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+/// The code that `mutate` and `run` work on: mutants inside the selected
+/// functions and on lines changed since a git revision. The default selects
+/// every mutant.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection {
+    /// Function selectors, as matched by [`matches_selector`]
+    pub functions: Vec<String>,
+    /// Git revision whose diff limits mutants to changed lines
+    pub since: Option<String>,
+}
+
+impl Selection {
+    /// Whether every mutant is selected
+    pub fn is_everything(&self) -> bool {
+        self.functions.is_empty() && self.since.is_none()
+    }
+
+    /// Keep only the selected mutants of a target declaring `functions`
+    pub fn filter(
+        &self,
+        target: &Target,
+        functions: &[FunctionSpan],
+        mutants: Vec<Mutant>,
+    ) -> Vec<Mutant> {
+        let mutants = filter_to_functions(target, functions, mutants, &self.functions);
+        changes::filter_to_changed_lines(target, mutants, self.since.as_deref())
+    }
+}
+
+/// Find the innermost function containing a byte offset
+pub fn enclosing_function(functions: &[FunctionSpan], byte: usize) -> Option<&FunctionSpan> {
+    functions
+        .iter()
+        .filter(|f| f.start_byte <= byte && byte < f.end_byte)
+        .min_by_key(|f| f.end_byte - f.start_byte)
+}
+
+/// Check whether a function name matches a selector, either exactly (ignoring
+/// whitespace) or by its bare name, so `receive` selects every receiver and
+/// `sum` selects `Point.sum`
+pub fn matches_selector(name: &str, selector: &str) -> bool {
+    let name = strip_whitespace(name);
+    let selector = strip_whitespace(selector);
+    name == selector || bare_name(&name) == selector
+}
+
+/// Keep only the mutants inside functions matching one of the selectors; no
+/// selectors keeps every mutant
+pub fn filter_to_functions(
+    target: &Target,
+    functions: &[FunctionSpan],
+    mutants: Vec<Mutant>,
+    selectors: &[String],
+) -> Vec<Mutant> {
+    if selectors.is_empty() {
+        return mutants;
+    }
+    let selected = |m: &Mutant| {
+        enclosing_function(functions, m.byte_offset as usize)
+            .is_some_and(|f| selectors.iter().any(|s| matches_selector(&f.name, s)))
+    };
+    let kept: Vec<Mutant> = mutants.into_iter().filter(selected).collect();
+    debug!(
+        "{}: {} mutant(s) inside functions matching {selectors:?}",
+        target.path.display(),
+        kept.len()
+    );
+    kept
+}
+
+/// Log mutant counts per enclosing function
+pub fn report_functions(target: &Target, functions: &[FunctionSpan], mutants: &[Mutant]) {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for m in mutants {
+        let name = enclosing_function(functions, m.byte_offset as usize)
+            .map_or("<top level>", |f| f.name.as_str());
+        *counts.entry(name).or_default() += 1;
+    }
+    for (name, count) in counts {
+        debug!("{}: {name}: {count} mutant(s)", target.path.display());
+    }
+}
+
+fn strip_whitespace(text: &str) -> String {
+    text.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Drop a parameter list and a `Type.` receiver from a function name
+fn bare_name(name: &str) -> &str {
+    let name = name.split('(').next().unwrap_or(name);
+    name.rsplit('.').next().unwrap_or(name)
+}
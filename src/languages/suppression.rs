@@ -0,0 +1,202 @@
+use std::ops::RangeInclusive;
+
+use log::debug;
+use mewt::types::{Mutant, Target};
+use mewt::utils::visit_nodes_with_cursor;
+use tree_sitter::Node;
+
+use crate::languages::scope::FunctionSpan;
+
+/// Comment node kind shared by the FunC, Tact and Tolk grammars
+const COMMENT: &str = "comment";
+/// Prefix shared by every suppression directive
+const DIRECTIVE: &str = "muton-ignore";
+/// Markers that open a comment in FunC, Tact and Tolk
+const COMMENT_OPENERS: &[&str] = &["//", "/*", ";;", "{-"];
+
+/// A suppression directive found in a comment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Directive {
+    /// `muton-ignore`: the line the comment trails, or the next line
+    Line,
+    /// `muton-ignore-next-line`
+    NextLine,
+    /// `muton-ignore-start`, up to the matching `muton-ignore-end`
+    Start,
+    /// `muton-ignore-end`
+    End,
+    /// `muton-ignore-function`: the next function, receiver or method
+    Function,
+}
+
+/// Lines on which some or all mutation slugs are suppressed
+#[derive(Debug, Clone)]
+struct Rule {
+    lines: RangeInclusive<u32>,
+    /// Suppressed slugs; empty means every slug
+    slugs: Vec<String>,
+}
+
+/// Suppression rules collected from `muton-ignore` comments in a source file
+#[derive(Debug, Clone, Default)]
+pub struct Suppressions {
+    rules: Vec<Rule>,
+}
+
+impl Suppressions {
+    /// Collect the suppression directives of every comment in a tree, given
+    /// the functions declared in it
+    pub fn from_tree(root: Node, source: &str, functions: &[FunctionSpan]) -> Self {
+        let mut rules = Vec::new();
+        let mut open: Option<(u32, Vec<String>)> = None;
+        let mut cursor = root.walk();
+        visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+            if node.kind() != COMMENT {
+                return;
+            }
+            let Some((directive, slugs)) = parse_directive(&source[node.byte_range()]) else {
+                return;
+            };
+            let start_line = node.start_position().row as u32;
+            let end_line = node.end_position().row as u32;
+            match directive {
+                Directive::Line if is_trailing(&node, source) => rules.push(Rule {
+                    lines: start_line..=start_line,
+                    slugs,
+                }),
+                Directive::Line | Directive::NextLine => rules.push(Rule {
+                    lines: end_line + 1..=end_line + 1,
+                    slugs,
+                }),
+                Directive::Function => {
+                    let next = functions
+                        .iter()
+                        .filter(|f| f.start_byte >= node.end_byte())
+                        .min_by_key(|f| f.start_byte);
+                    if let Some(function) = next {
+                        rules.push(Rule {
+                            lines: line_of(source, function.start_byte)
+                                ..=line_of(source, function.end_byte),
+                            slugs,
+                        });
+                    }
+                }
+                Directive::Start => {
+                    if open.is_none() {
+                        open = Some((start_line, slugs));
+                    }
+                }
+                Directive::End => {
+                    if let Some((first, slugs)) = open.take() {
+                        rules.push(Rule {
+                            lines: first..=end_line,
+                            slugs,
+                        });
+                    }
+                }
+            }
+        });
+        // An unterminated block runs to the end of the file
+        if let Some((first, slugs)) = open {
+            rules.push(Rule {
+                lines: first..=u32::MAX,
+                slugs,
+            });
+        }
+        Self { rules }
+    }
+
+    /// Check whether a mutation slug is suppressed on any of a range of
+    /// (0-based) lines
+    pub fn is_suppressed(&self, lines: RangeInclusive<u32>, slug: &str) -> bool {
+        self.rules.iter().any(|rule| {
+            rule.lines.start() <= lines.end()
+                && lines.start() <= rule.lines.end()
+                && (rule.slugs.is_empty() || rule.slugs.iter().any(|s| s == slug))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+/// Drop the mutants suppressed by `muton-ignore` comments. A mutant that spans
+/// several lines is suppressed if any of them is ignored.
+pub fn filter_suppressed(
+    target: &Target,
+    root: Node,
+    functions: &[FunctionSpan],
+    mutants: Vec<Mutant>,
+) -> Vec<Mutant> {
+    let suppressions = Suppressions::from_tree(root, &target.text, functions);
+    if suppressions.is_empty() {
+        return mutants;
+    }
+    let before = mutants.len();
+    let kept: Vec<Mutant> = mutants
+        .into_iter()
+        .filter(|m| {
+            let (first, last) = m.get_lines();
+            !suppressions.is_suppressed(first - 1..=last - 1, &m.mutation_slug)
+        })
+        .collect();
+    debug!(
+        "{}: {} mutant(s) suppressed by muton-ignore comments",
+        target.path.display(),
+        before - kept.len()
+    );
+    kept
+}
+
+/// Parse a directive and its optional `: SLUG, SLUG` list out of a comment.
+/// The directive must open the comment text, so comments that only mention it
+/// are not directives.
+fn parse_directive(comment: &str) -> Option<(Directive, Vec<String>)> {
+    let text = COMMENT_OPENERS
+        .iter()
+        .find_map(|opener| comment.strip_prefix(opener))
+        .unwrap_or(comment);
+    // Doc comments such as `///` and `;;;` repeat the marker
+    let text = text.trim_start_matches(['/', '*', ';']).trim_start();
+    let rest = text.strip_prefix(DIRECTIVE)?;
+    let (directive, rest) = if let Some(rest) = rest.strip_prefix("-next-line") {
+        (Directive::NextLine, rest)
+    } else if let Some(rest) = rest.strip_prefix("-start") {
+        (Directive::Start, rest)
+    } else if let Some(rest) = rest.strip_prefix("-end") {
+        (Directive::End, rest)
+    } else if let Some(rest) = rest.strip_prefix("-function") {
+        (Directive::Function, rest)
+    } else {
+        (Directive::Line, rest)
+    };
+    // Reject longer words such as `muton-ignored`
+    if rest.starts_with(|c: char| c.is_alphanumeric() || c == '-' || c == '_') {
+        return None;
+    }
+    let slugs = match rest.trim_start().strip_prefix(':') {
+        Some(list) => list
+            .trim_end()
+            .trim_end_matches("-}")
+            .trim_end_matches("*/")
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect(),
+        None => Vec::new(),
+    };
+    Some((directive, slugs))
+}
+
+/// The 0-based line of a byte offset
+fn line_of(source: &str, byte: usize) -> u32 {
+    source[..byte].matches('\n').count() as u32
+}
+
+/// A comment trails code when something other than whitespace precedes it on
+/// its line
+fn is_trailing(node: &Node, source: &str) -> bool {
+    let line_start = source[..node.start_byte()].rfind('\n').map_or(0, |i| i + 1);
+    !source[line_start..node.start_byte()].trim().is_empty()
+}
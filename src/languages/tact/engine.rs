@@ -7,8 +7,13 @@ use mewt::types::{Mutant, Mutation, Target};
 use mewt::utils::{node_text, parse_source};
 use tree_sitter::Language as TsLanguage;
 
+use crate::config::{RuleConfig, muton_config};
+use crate::languages::rules::{self, QueryRule};
+use crate::languages::scope::Selection;
 use crate::languages::tact::kinds::TACT_MUTATIONS;
+use crate::languages::tact::patterns as tact_patterns;
 use crate::languages::tact::syntax::{fields, nodes};
+use crate::languages::{self, diagnostics};
 
 static TACT_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
 
@@ -17,7 +22,10 @@ unsafe extern "C" {
 }
 
 pub struct TactLanguageEngine {
-    mutations: Vec<Mutation>,
+    builtin: Vec<Mutation>,
+    rules: OnceLock<Vec<QueryRule>>,
+    mutations: OnceLock<Vec<Mutation>>,
+    selection: Selection,
 }
 
 impl Default for TactLanguageEngine {
@@ -28,13 +36,50 @@ impl Default for TactLanguageEngine {
 
 impl TactLanguageEngine {
     pub fn new() -> Self {
-        let mut mutations: Vec<Mutation> = Vec::new();
-        mutations.extend_from_slice(COMMON_MUTATIONS);
-        mutations.extend_from_slice(TACT_MUTATIONS);
-        Self { mutations }
+        let mut builtin: Vec<Mutation> = Vec::new();
+        builtin.extend_from_slice(COMMON_MUTATIONS);
+        builtin.extend_from_slice(TACT_MUTATIONS);
+        Self {
+            builtin,
+            rules: OnceLock::new(),
+            mutations: OnceLock::new(),
+            selection: Selection::default(),
+        }
+    }
+
+    /// Generate only the mutants inside a selection of functions and lines
+    pub fn with_selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Create an engine with the given user-defined rules instead of the ones
+    /// configured in `muton.toml`
+    pub fn with_rules(rules: &[RuleConfig]) -> Self {
+        let engine = Self::new();
+        let compiled = rules::compile_rules(engine.name(), tact_language(), &engine.builtin, rules);
+        let _ = engine.rules.set(compiled);
+        engine
+    }
+
+    /// User-defined rules, loaded on first use so that creating an engine does
+    /// not read the config file
+    fn rules(&self) -> &[QueryRule] {
+        self.rules.get_or_init(|| {
+            rules::compile_rules(
+                self.name(),
+                tact_language(),
+                &self.builtin,
+                &muton_config().rules(),
+            )
+        })
     }
 }
 
+pub(crate) fn tact_language() -> &'static TsLanguage {
+    TACT_LANGUAGE.get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_tact()) })
+}
+
 impl LanguageEngine for TactLanguageEngine {
     fn name(&self) -> &'static str {
         "Tact"
@@ -45,21 +90,31 @@ impl LanguageEngine for TactLanguageEngine {
     }
 
     fn get_mutations(&self) -> &[Mutation] {
-        &self.mutations
+        self.mutations.get_or_init(|| {
+            let mut mutations = self.builtin.clone();
+            mutations.extend(self.rules().iter().map(|rule| rule.mutation.clone()));
+            mutations
+        })
     }
 
     fn mutate(&self, target: &Target) -> Vec<Mutant> {
         let source = &target.text;
-        let language =
-            TACT_LANGUAGE.get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_tact()) });
+        let language = tact_language();
+        // Strict parse mode is enforced before generation starts, so syntax
+        // errors are only logged here
         let tree = match parse_source(source, language) {
             Some(t) => t,
-            None => return Vec::new(),
+            None => {
+                diagnostics::log_parse_errors(target, &[diagnostics::parse_failure(target)]);
+                return Vec::new();
+            }
         };
         let root = tree.root_node();
+        let errors = diagnostics::parse_errors(root, source);
+        diagnostics::log_parse_errors(target, &errors);
 
         let mut all_mutants = Vec::new();
-        for m in &self.mutations {
+        for m in self.get_mutations() {
             match m.slug {
                 "ER" => {
                     all_mutants.extend(
@@ -85,6 +140,7 @@ impl LanguageEngine for TactLanguageEngine {
                             },
                         )
                         .into_iter()
+                        .map(|p| tact_patterns::absorb_terminator(p, source))
                         .map(|p| Mutant::from_partial(p, target, "ER")),
                     );
                 }
@@ -108,6 +164,7 @@ impl LanguageEngine for TactLanguageEngine {
                             " */",
                         )
                         .into_iter()
+                        .map(|p| tact_patterns::absorb_terminator(p, source))
                         .map(|p| Mutant::from_partial(p, target, "CR")),
                     );
                 }
@@ -291,6 +348,66 @@ impl LanguageEngine for TactLanguageEngine {
                     .into_iter()
                     .map(|p| Mutant::from_partial(p, target, "TF")),
                 ),
+                "SVZ" => all_mutants.extend(
+                    tact_patterns::zero_send_value(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "SVZ")),
+                ),
+                "SBF" => all_mutants.extend(
+                    tact_patterns::flip_send_bounce(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "SBF")),
+                ),
+                "SMS" => all_mutants.extend(
+                    tact_patterns::shuffle_send_mode(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "SMS")),
+                ),
+                "SBR" => all_mutants.extend(
+                    tact_patterns::remove_send_body(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "SBR")),
+                ),
+                "RHE" => all_mutants.extend(
+                    tact_patterns::empty_handler_bodies(root, source, nodes::RECEIVE_FUNCTION)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "RHE")),
+                ),
+                "BHE" => all_mutants.extend(
+                    tact_patterns::empty_handler_bodies(root, source, nodes::BOUNCED_FUNCTION)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "BHE")),
+                ),
+                "EHE" => all_mutants.extend(
+                    tact_patterns::empty_handler_bodies(root, source, nodes::EXTERNAL_FUNCTION)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "EHE")),
+                ),
+                "RD" => all_mutants.extend(
+                    tact_patterns::remove_guards(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "RD")),
+                ),
+                "RN" => all_mutants.extend(
+                    tact_patterns::negate_guards(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "RN")),
+                ),
+                "RT" => all_mutants.extend(
+                    tact_patterns::pass_guards(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "RT")),
+                ),
+                "SIR" => all_mutants.extend(
+                    tact_patterns::replace_sender(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "SIR")),
+                ),
+                "AFR" => all_mutants.extend(
+                    tact_patterns::replace_address_fields(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "AFR")),
+                ),
                 "LC" => all_mutants.extend(
                     patterns::shuffle_nodes(
                         root,
@@ -313,15 +430,26 @@ impl LanguageEngine for TactLanguageEngine {
                     .into_iter()
                     .map(|p| Mutant::from_partial(p, target, "NR")),
                 ),
-                _ => {
-                    panic!(
-                        "Unknown mutation slug encountered in Tact engine: {}",
-                        m.slug
-                    );
-                }
+                slug => match self.rules().iter().find(|rule| rule.mutation.slug == slug) {
+                    Some(rule) => all_mutants.extend(
+                        rule.apply(root, source)
+                            .into_iter()
+                            .map(|p| Mutant::from_partial(p, target, slug)),
+                    ),
+                    None => panic!("Unknown mutation slug encountered in Tact engine: {slug}"),
+                },
             }
         }
-        all_mutants
+
+        let functions = tact_patterns::function_spans(root, source);
+        languages::finish_mutants(
+            target,
+            language,
+            root,
+            &functions,
+            &self.selection,
+            all_mutants,
+        )
     }
 }
 
@@ -21,4 +21,64 @@ pub const TACT_MUTATIONS: &[Mutation] = &[
         description: "Ternary False: Hardcode ternary condition to false",
         severity: MutationSeverity::Low,
     },
+    Mutation {
+        slug: "SVZ",
+        description: "Send Value Zero: replace the value of SendParameters/MessageParameters with zero",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "SBF",
+        description: "Send Bounce Flip: flip the bounce flag of an outgoing message",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "SMS",
+        description: "Send Mode Shuffle: replace a base send mode or send mode flag constant with another of its kind",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "SBR",
+        description: "Send Body Removal: drop the body of an outgoing message",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "RHE",
+        description: "Receive Handler Empty: remove the whole body of a receive handler",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "BHE",
+        description: "Bounced Handler Empty: remove the whole body of a bounced handler",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "EHE",
+        description: "External Handler Empty: remove the whole body of an external handler",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "RD",
+        description: "Require Deletion: remove a require/throwUnless/throwIf/nativeThrowUnless statement",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "RN",
+        description: "Require Negation: negate the condition of a require/throwUnless/throwIf/nativeThrowUnless call",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "RT",
+        description: "Require True: make a require/throwUnless/throwIf/nativeThrowUnless guard always pass",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "SIR",
+        description: "Sender Identity Replacement: replace sender() or context().sender with myAddress() or an Address field it is not compared with",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "AFR",
+        description: "Address Field Replacement: compare against myAddress() or another Address field instead of self.<field>",
+        severity: MutationSeverity::High,
+    },
 ];
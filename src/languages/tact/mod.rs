@@ -1,3 +1,4 @@
 pub mod engine;
 pub mod kinds;
+pub mod patterns;
 pub mod syntax;
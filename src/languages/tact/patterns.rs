@@ -0,0 +1,473 @@
+use std::iter::successors;
+
+use mewt::types::PartialMutant;
+use mewt::utils::{is_in_comment, node_text, visit_nodes_with_cursor};
+use tree_sitter::Node;
+
+use crate::languages::patterns::{remove_list_item, remove_statement, replace_node};
+use crate::languages::scope::FunctionSpan;
+use crate::languages::tact::syntax::{fields, nodes};
+
+/// Struct types accepted by `send()`, `message()` and `deploy()`
+const SEND_PARAMETER_STRUCTS: &[&str] =
+    &["SendParameters", "MessageParameters", "DeployParameters"];
+/// Mutually exclusive base send modes that `SMS` rotates between
+const SEND_MODE_BASES: &[&str] = &["SendRemainingValue", "SendRemainingBalance"];
+/// Send mode flags that `SMS` rotates between
+const SEND_MODE_FLAGS: &[&str] = &["SendIgnoreErrors", "SendPayGasSeparately"];
+/// Guard calls as (name, condition argument, condition value that never throws)
+const GUARDS: &[(&str, usize, &str)] = &[
+    ("require", 0, "true"),
+    ("throwUnless", 1, "true"),
+    ("throwIf", 1, "false"),
+    ("nativeThrowUnless", 1, "true"),
+];
+
+/// Visit each `field: value` initializer of a send parameters struct instance
+fn visit_send_fields(root: Node, source: &str, callback: &mut dyn FnMut(&str, Node<'_>)) {
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if node.kind() != nodes::INSTANCE_EXPRESSION || is_in_comment(&node) {
+            return;
+        }
+        let is_send_struct = node
+            .child_by_field_name(fields::NAME)
+            .is_some_and(|name| SEND_PARAMETER_STRUCTS.contains(&node_text(&name, source)));
+        if !is_send_struct {
+            return;
+        }
+        let Some(args) = node.child_by_field_name(fields::ARGUMENTS) else {
+            return;
+        };
+        let mut c = args.walk();
+        for arg in args.named_children(&mut c) {
+            if arg.kind() != nodes::INSTANCE_ARGUMENT {
+                continue;
+            }
+            if let Some(name) = arg.child_by_field_name(fields::NAME) {
+                callback(node_text(&name, source), arg);
+            }
+        }
+    });
+}
+
+/// Visit each argument value of `self.<method>(...)` calls, with the method name
+/// and argument position
+fn visit_self_call_args(root: Node, source: &str, callback: &mut dyn FnMut(&str, usize, Node<'_>)) {
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if node.kind() != nodes::METHOD_CALL_EXPRESSION || is_in_comment(&node) {
+            return;
+        }
+        let on_self = node
+            .child_by_field_name(fields::OBJECT)
+            .is_some_and(|object| object.kind() == nodes::SELF);
+        if !on_self {
+            return;
+        }
+        let (Some(name), Some(args)) = (
+            node.child_by_field_name(fields::NAME),
+            node.child_by_field_name(fields::ARGUMENTS),
+        ) else {
+            return;
+        };
+        let method = node_text(&name, source);
+        let mut c = args.walk();
+        let values = args
+            .named_children(&mut c)
+            .filter_map(|arg| arg.child_by_field_name(fields::VALUE));
+        for (index, value) in values.enumerate() {
+            callback(method, index, value);
+        }
+    });
+}
+
+/// Replace the `value` of a send parameters struct with zero
+pub fn zero_send_value(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_send_fields(root, source, &mut |field, arg| {
+        if field != "value" {
+            return;
+        }
+        if let Some(value) = arg.child_by_field_name(fields::VALUE)
+            && node_text(&value, source) != "0"
+        {
+            mutants.push(replace_node(&value, source, "0"));
+        }
+    });
+    mutants
+}
+
+/// Flip the `bounce` flag of a send parameters struct or a `self.forward()` call
+pub fn flip_send_bounce(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_send_fields(root, source, &mut |field, arg| {
+        if field != "bounce" {
+            return;
+        }
+        if let Some(value) = arg.child_by_field_name(fields::VALUE) {
+            mutants.push(negate_bool(&value, source));
+        }
+    });
+    visit_self_call_args(root, source, &mut |method, index, value| {
+        if method == "forward" && index == 2 {
+            mutants.push(negate_bool(&value, source));
+        }
+    });
+    mutants
+}
+
+/// Replace a base send mode constant with the other base mode, and a send
+/// mode flag with each other flag, leaving out constants already in the mode
+/// so two base modes are never combined
+pub fn shuffle_send_mode(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_send_fields(root, source, &mut |field, arg| {
+        if field != "mode" {
+            return;
+        }
+        let Some(value) = arg.child_by_field_name(fields::VALUE) else {
+            return;
+        };
+        let mode_text = node_text(&value, source);
+        let mut cursor = value.walk();
+        visit_nodes_with_cursor(value, &mut cursor, &mut |node| {
+            if node.kind() != nodes::IDENTIFIER {
+                return;
+            }
+            let name = node_text(&node, source);
+            let Some(group) = [SEND_MODE_BASES, SEND_MODE_FLAGS]
+                .into_iter()
+                .find(|group| group.contains(&name))
+            else {
+                return;
+            };
+            for replacement in group.iter().filter(|m| !mode_text.contains(**m)) {
+                mutants.push(replace_node(&node, source, *replacement));
+            }
+        });
+    });
+    mutants
+}
+
+/// Drop the `body` of a send parameters struct, or pass `null` as the body of
+/// `self.reply()`, `self.notify()` and `self.forward()`
+pub fn remove_send_body(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_send_fields(root, source, &mut |field, arg| {
+        if field == "body" {
+            mutants.push(remove_list_item(&arg, source));
+        }
+    });
+    visit_self_call_args(root, source, &mut |method, index, value| {
+        let is_body = matches!((method, index), ("reply" | "notify", 0) | ("forward", 1));
+        if is_body && value.kind() != nodes::NULL {
+            mutants.push(replace_node(&value, source, "null"));
+        }
+    });
+    mutants
+}
+
+fn negate_bool(node: &Node, source: &str) -> PartialMutant {
+    let new_text = match node_text(node, source) {
+        "true" => "false".to_string(),
+        "false" => "true".to_string(),
+        other => format!("!({other})"),
+    };
+    replace_node(node, source, new_text)
+}
+
+/// Replace the body of every handler of the given kind (`receive`, `bounced`,
+/// `external`) with an empty block
+pub fn empty_handler_bodies(root: Node, source: &str, kind: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if node.kind() != kind || is_in_comment(&node) {
+            return;
+        }
+        if let Some(body) = node.child_by_field_name(fields::BODY)
+            && body.named_child_count() > 0
+        {
+            mutants.push(replace_node(&body, source, "{}"));
+        }
+    });
+    mutants
+}
+
+/// Visit each guard call with its condition argument and the condition value
+/// that makes it pass
+fn visit_guards(root: Node, source: &str, callback: &mut dyn FnMut(Node<'_>, Node<'_>, &str)) {
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if node.kind() != nodes::STATIC_CALL_EXPRESSION || is_in_comment(&node) {
+            return;
+        }
+        let Some(name) = node.child_by_field_name(fields::NAME) else {
+            return;
+        };
+        let name = node_text(&name, source);
+        let Some((_, index, pass)) = GUARDS.iter().find(|(guard, ..)| *guard == name) else {
+            return;
+        };
+        let condition = node
+            .child_by_field_name(fields::ARGUMENTS)
+            .and_then(|args| {
+                let mut c = args.walk();
+                args.named_children(&mut c)
+                    .nth(*index)
+                    .and_then(|arg| arg.child_by_field_name(fields::VALUE))
+            });
+        if let Some(condition) = condition {
+            callback(node, condition, pass);
+        }
+    });
+}
+
+/// Remove guard statements such as `require(cond, "msg");`
+pub fn remove_guards(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_guards(root, source, &mut |call, _, _| {
+        if let Some(statement) = call.parent()
+            && statement.kind() == nodes::EXPRESSION_STATEMENT
+            && statement.byte_range() == call.byte_range()
+        {
+            mutants.push(remove_statement(&statement, source));
+        }
+    });
+    mutants
+}
+
+/// Negate the condition of guard calls
+pub fn negate_guards(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_guards(root, source, &mut |_, condition, _| {
+        mutants.push(negate_bool(&condition, source));
+    });
+    mutants
+}
+
+/// Replace the condition of guard calls with the constant that never throws,
+/// as in `require(true, "msg")` or `throwIf(code, false)`
+pub fn pass_guards(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_guards(root, source, &mut |_, condition, pass| {
+        if node_text(&condition, source) != pass {
+            mutants.push(replace_node(&condition, source, pass));
+        }
+    });
+    mutants
+}
+
+/// Visit each contract and trait with the names of its `Address` fields and
+/// contract parameters
+fn visit_address_fields(root: Node, source: &str, callback: &mut dyn FnMut(Node<'_>, &[&str])) {
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if matches!(node.kind(), nodes::CONTRACT | nodes::TRAIT) {
+            callback(node, &address_fields(&node, source));
+        }
+    });
+}
+
+/// Names of the `Address` fields and contract parameters of a contract or
+/// trait; optional `Address?` fields are left out
+fn address_fields<'a>(scope: &Node, source: &'a str) -> Vec<&'a str> {
+    let mut fields_found = Vec::new();
+    for (list, kind) in [
+        (fields::PARAMETERS, nodes::PARAMETER),
+        (fields::BODY, nodes::STORAGE_VARIABLE),
+    ] {
+        let Some(list) = scope.child_by_field_name(list) else {
+            continue;
+        };
+        let mut c = list.walk();
+        for field in list.named_children(&mut c).filter(|n| n.kind() == kind) {
+            let is_address = field.child_by_field_name(fields::TYPE).is_some_and(|t| {
+                node_text(&t, source) == "Address"
+                    && t.next_sibling().is_none_or(|n| n.kind() != "?")
+            });
+            if is_address && let Some(name) = field.child_by_field_name(fields::NAME) {
+                fields_found.push(node_text(&name, source));
+            }
+        }
+    }
+    fields_found
+}
+
+/// Replace `sender()` and `context().sender` with `myAddress()` and, inside a
+/// contract or trait, each of its `Address` fields. In `sender() == self.x`
+/// and `sender() != self.x` the compared field `x` is left out, as it would
+/// compare the field with itself, and so is `x` in `self.x = sender()`.
+pub fn replace_sender(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if is_in_comment(&node) || !is_sender(&node, source) {
+            return;
+        }
+        mutants.push(replace_node(&node, source, "myAddress()"));
+        let Some(scope) = successors(node.parent(), Node::parent)
+            .find(|n| matches!(n.kind(), nodes::CONTRACT | nodes::TRAIT))
+        else {
+            return;
+        };
+        let paired = paired_field(&node, source);
+        for field in address_fields(&scope, source) {
+            if Some(field) != paired {
+                mutants.push(replace_node(&node, source, format!("self.{field}")));
+            }
+        }
+    });
+    mutants
+}
+
+/// The field on the other side of an `==`/`!=` comparison with `node`, as
+/// `owner` in `sender() == self.owner`, or the field `node` is assigned to, as
+/// in `self.owner = sender()`
+fn paired_field<'a>(node: &Node, source: &'a str) -> Option<&'a str> {
+    let parent = node.parent()?;
+    match parent.kind() {
+        nodes::BINARY_EXPRESSION => {
+            parent
+                .child_by_field_name(fields::OPERATOR)
+                .filter(|op| matches!(op.kind(), "==" | "!="))?;
+        }
+        nodes::ASSIGNMENT_STATEMENT => {
+            parent
+                .child_by_field_name(fields::RIGHT)
+                .filter(|right| right.id() == node.id())?;
+        }
+        _ => return None,
+    }
+    let other = [fields::LEFT, fields::RIGHT]
+        .into_iter()
+        .filter_map(|side| parent.child_by_field_name(side))
+        .find(|operand| operand.id() != node.id())?;
+    self_field(&other, source)
+}
+
+/// Replace a `self.<field>` operand of `==`/`!=` whose field is an `Address`
+/// with `myAddress()` and each other `Address` field, except the field on the
+/// other side, which would compare it with itself
+pub fn replace_address_fields(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_address_fields(root, source, &mut |scope, address_fields| {
+        let mut cursor = scope.walk();
+        visit_nodes_with_cursor(scope, &mut cursor, &mut |node| {
+            if node.kind() != nodes::BINARY_EXPRESSION || is_in_comment(&node) {
+                return;
+            }
+            let is_equality = node
+                .child_by_field_name(fields::OPERATOR)
+                .is_some_and(|op| matches!(op.kind(), "==" | "!="));
+            if !is_equality {
+                return;
+            }
+            for side in [fields::LEFT, fields::RIGHT] {
+                let Some(operand) = node.child_by_field_name(side) else {
+                    continue;
+                };
+                let Some(field) = self_field(&operand, source) else {
+                    continue;
+                };
+                if !address_fields.contains(&field) {
+                    continue;
+                }
+                mutants.push(replace_node(&operand, source, "myAddress()"));
+                let compared = paired_field(&operand, source);
+                for other in address_fields
+                    .iter()
+                    .filter(|&&f| f != field && Some(f) != compared)
+                {
+                    mutants.push(replace_node(&operand, source, format!("self.{other}")));
+                }
+            }
+        });
+    });
+    mutants
+}
+
+/// Whether a node is `sender()` or `context().sender`
+fn is_sender(node: &Node, source: &str) -> bool {
+    match node.kind() {
+        nodes::STATIC_CALL_EXPRESSION => is_call_without_args(node, source, "sender"),
+        nodes::FIELD_ACCESS_EXPRESSION => {
+            node.child_by_field_name(fields::NAME)
+                .is_some_and(|name| node_text(&name, source) == "sender")
+                && node
+                    .child_by_field_name(fields::OBJECT)
+                    .is_some_and(|object| is_call_without_args(&object, source, "context"))
+        }
+        _ => false,
+    }
+}
+
+fn is_call_without_args(node: &Node, source: &str, name: &str) -> bool {
+    node.kind() == nodes::STATIC_CALL_EXPRESSION
+        && node
+            .child_by_field_name(fields::NAME)
+            .is_some_and(|n| node_text(&n, source) == name)
+        && node
+            .child_by_field_name(fields::ARGUMENTS)
+            .is_some_and(|args| args.named_child_count() == 0)
+}
+
+/// The field name of a `self.<field>` access
+fn self_field<'a>(node: &Node, source: &'a str) -> Option<&'a str> {
+    if node.kind() != nodes::FIELD_ACCESS_EXPRESSION {
+        return None;
+    }
+    node.child_by_field_name(fields::OBJECT)
+        .filter(|object| object.kind() == nodes::SELF)?;
+    let name = node.child_by_field_name(fields::NAME)?;
+    Some(node_text(&name, source))
+}
+
+/// Find the span of every function and handler. Handlers are named by their
+/// parameter, as in `receive("withdraw")` or `bounced(msg: bounced<Transfer>)`.
+pub fn function_spans(root: Node, source: &str) -> Vec<FunctionSpan> {
+    let mut spans = Vec::new();
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        let handler = match node.kind() {
+            nodes::RECEIVE_FUNCTION => "receive",
+            nodes::BOUNCED_FUNCTION => "bounced",
+            nodes::EXTERNAL_FUNCTION => "external",
+            nodes::INIT_FUNCTION => "init",
+            nodes::STORAGE_FUNCTION | nodes::GLOBAL_FUNCTION => "",
+            _ => return,
+        };
+        let name = if handler.is_empty() {
+            match node.child_by_field_name(fields::NAME) {
+                Some(name) => node_text(&name, source).to_string(),
+                None => return,
+            }
+        } else {
+            let parameter = node
+                .child_by_field_name(fields::PARAMETER)
+                .map_or("", |p| node_text(&p, source));
+            format!("{handler}({parameter})")
+        };
+        spans.push(FunctionSpan {
+            name,
+            start_byte: node.start_byte(),
+            end_byte: node.end_byte(),
+        });
+    });
+    spans
+}
+
+/// Extend an `ER` or `CR` statement mutant over the `;` that terminates it.
+/// The Tact grammar keeps the `;` as a sibling of the statement node, so
+/// replacing or commenting out only the node would leave a stray `;` behind.
+pub fn absorb_terminator(mut mutant: PartialMutant, source: &str) -> PartialMutant {
+    let end = mutant.byte_offset as usize + mutant.old_text.len();
+    let rest = &source[end..];
+    let trimmed = rest.trim_start();
+    if trimmed.starts_with(';') {
+        let len = rest.len() - trimmed.len() + 1;
+        mutant.old_text.push_str(&rest[..len]);
+    }
+    mutant
+}
@@ -1,20 +1,40 @@
 pub mod nodes {
+    pub const ASSIGNMENT_STATEMENT: &str = "assignment_statement";
     pub const AUGMENTED_ASSIGNMENT_STATEMENT: &str = "augmented_assignment_statement";
     pub const BINARY_EXPRESSION: &str = "binary_expression";
     pub const BOOLEAN: &str = "boolean";
+    pub const BOUNCED_FUNCTION: &str = "bounced_function";
     pub const DESTRUCT_STATEMENT: &str = "destruct_statement";
     pub const DO_UNTIL_STATEMENT: &str = "do_until_statement";
     pub const EXPRESSION_STATEMENT: &str = "expression_statement";
+    pub const EXTERNAL_FUNCTION: &str = "external_function";
     pub const FOREACH_STATEMENT: &str = "foreach_statement";
+    pub const GLOBAL_FUNCTION: &str = "global_function";
+    pub const IDENTIFIER: &str = "identifier";
     pub const IF_STATEMENT: &str = "if_statement";
+    pub const INIT_FUNCTION: &str = "init_function";
+    pub const INSTANCE_ARGUMENT: &str = "instance_argument";
+    pub const INSTANCE_EXPRESSION: &str = "instance_expression";
     pub const LET_STATEMENT: &str = "let_statement";
     pub const METHOD_CALL_EXPRESSION: &str = "method_call_expression";
+    pub const NULL: &str = "null";
+    pub const RECEIVE_FUNCTION: &str = "receive_function";
     pub const REPEAT_STATEMENT: &str = "repeat_statement";
     pub const RETURN_STATEMENT: &str = "return_statement";
+    pub const SELF: &str = "self";
+    pub const STORAGE_FUNCTION: &str = "storage_function";
     pub const STATIC_CALL_EXPRESSION: &str = "static_call_expression";
     pub const TERNARY_EXPRESSION: &str = "ternary_expression";
     pub const UNARY_EXPRESSION: &str = "unary_expression";
     pub const WHILE_STATEMENT: &str = "while_statement";
+
+    // Contract and trait declarations
+    pub const CONTRACT: &str = "contract";
+    pub const FIELD_ACCESS_EXPRESSION: &str = "field_access_expression";
+    pub const PARAMETER: &str = "parameter";
+    pub const STORAGE_VARIABLE: &str = "storage_variable";
+    pub const TRAIT: &str = "trait";
+
     pub const BREAK_STATEMENT: &str = "break_statement";
     pub const CONTINUE_STATEMENT: &str = "continue_statement";
 }
@@ -24,4 +44,13 @@ pub mod fields {
     pub const ARGUMENTS: &str = "arguments";
     pub const OPERATOR: &str = "operator";
     pub const ARGUMENT: &str = "argument";
+    pub const BODY: &str = "body";
+    pub const NAME: &str = "name";
+    pub const OBJECT: &str = "object";
+    pub const PARAMETER: &str = "parameter";
+    pub const VALUE: &str = "value";
+    pub const LEFT: &str = "left";
+    pub const RIGHT: &str = "right";
+    pub const PARAMETERS: &str = "parameters";
+    pub const TYPE: &str = "type";
 }
@@ -7,8 +7,13 @@ use mewt::types::{Mutant, Mutation, Target};
 use mewt::utils::{node_text, parse_source};
 use tree_sitter::Language as TsLanguage;
 
+use crate::config::{RuleConfig, muton_config};
+use crate::languages::rules::{self, QueryRule};
+use crate::languages::scope::Selection;
 use crate::languages::tolk::kinds::TOLK_MUTATIONS;
+use crate::languages::tolk::patterns as tolk_patterns;
 use crate::languages::tolk::syntax::{fields, nodes};
+use crate::languages::{self, diagnostics};
 
 static TOLK_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
 
@@ -17,7 +22,10 @@ unsafe extern "C" {
 }
 
 pub struct TolkLanguageEngine {
-    mutations: Vec<Mutation>,
+    builtin: Vec<Mutation>,
+    rules: OnceLock<Vec<QueryRule>>,
+    mutations: OnceLock<Vec<Mutation>>,
+    selection: Selection,
 }
 
 impl Default for TolkLanguageEngine {
@@ -28,11 +36,48 @@ impl Default for TolkLanguageEngine {
 
 impl TolkLanguageEngine {
     pub fn new() -> Self {
-        let mut mutations: Vec<Mutation> = Vec::new();
-        mutations.extend_from_slice(COMMON_MUTATIONS);
-        mutations.extend_from_slice(TOLK_MUTATIONS);
-        Self { mutations }
+        let mut builtin: Vec<Mutation> = Vec::new();
+        builtin.extend_from_slice(COMMON_MUTATIONS);
+        builtin.extend_from_slice(TOLK_MUTATIONS);
+        Self {
+            builtin,
+            rules: OnceLock::new(),
+            mutations: OnceLock::new(),
+            selection: Selection::default(),
+        }
+    }
+
+    /// Generate only the mutants inside a selection of functions and lines
+    pub fn with_selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
     }
+
+    /// Create an engine with the given user-defined rules instead of the ones
+    /// configured in `muton.toml`
+    pub fn with_rules(rules: &[RuleConfig]) -> Self {
+        let engine = Self::new();
+        let compiled = rules::compile_rules(engine.name(), tolk_language(), &engine.builtin, rules);
+        let _ = engine.rules.set(compiled);
+        engine
+    }
+
+    /// User-defined rules, loaded on first use so that creating an engine does
+    /// not read the config file
+    fn rules(&self) -> &[QueryRule] {
+        self.rules.get_or_init(|| {
+            rules::compile_rules(
+                self.name(),
+                tolk_language(),
+                &self.builtin,
+                &muton_config().rules(),
+            )
+        })
+    }
+}
+
+pub(crate) fn tolk_language() -> &'static TsLanguage {
+    TOLK_LANGUAGE.get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_tolk()) })
 }
 
 impl LanguageEngine for TolkLanguageEngine {
@@ -45,21 +90,31 @@ impl LanguageEngine for TolkLanguageEngine {
     }
 
     fn get_mutations(&self) -> &[Mutation] {
-        &self.mutations
+        self.mutations.get_or_init(|| {
+            let mut mutations = self.builtin.clone();
+            mutations.extend(self.rules().iter().map(|rule| rule.mutation.clone()));
+            mutations
+        })
     }
 
     fn mutate(&self, target: &Target) -> Vec<Mutant> {
         let source = &target.text;
-        let language =
-            TOLK_LANGUAGE.get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_tolk()) });
+        let language = tolk_language();
+        // Strict parse mode is enforced before generation starts, so syntax
+        // errors are only logged here
         let tree = match parse_source(source, language) {
             Some(t) => t,
-            None => return Vec::new(),
+            None => {
+                diagnostics::log_parse_errors(target, &[diagnostics::parse_failure(target)]);
+                return Vec::new();
+            }
         };
         let root = tree.root_node();
+        let errors = diagnostics::parse_errors(root, source);
+        diagnostics::log_parse_errors(target, &errors);
 
         let mut all_mutants = Vec::new();
-        for m in &self.mutations {
+        for m in self.get_mutations() {
             match m.slug {
                 "ER" => {
                     all_mutants.extend(
@@ -144,6 +199,84 @@ impl LanguageEngine for TolkLanguageEngine {
                         .map(|p| Mutant::from_partial(p, target, "WF")),
                     );
                 }
+                "RZ" => all_mutants.extend(
+                    patterns::replace_condition(
+                        root,
+                        source,
+                        nodes::REPEAT_STATEMENT,
+                        fields::COUNT,
+                        &["repeat"],
+                        "0",
+                    )
+                    .into_iter()
+                    .map(|p| Mutant::from_partial(p, target, "RZ")),
+                ),
+                "UF" => all_mutants.extend(
+                    patterns::replace_condition(
+                        root,
+                        source,
+                        nodes::DO_WHILE_STATEMENT,
+                        fields::CONDITION,
+                        &["while"],
+                        "false",
+                    )
+                    .into_iter()
+                    .map(|p| Mutant::from_partial(p, target, "UF")),
+                ),
+                "TT" => all_mutants.extend(
+                    patterns::replace_condition(
+                        root,
+                        source,
+                        nodes::TERNARY_OPERATOR,
+                        fields::CONDITION,
+                        &["?"],
+                        "true",
+                    )
+                    .into_iter()
+                    .map(|p| Mutant::from_partial(p, target, "TT")),
+                ),
+                "TF" => all_mutants.extend(
+                    patterns::replace_condition(
+                        root,
+                        source,
+                        nodes::TERNARY_OPERATOR,
+                        fields::CONDITION,
+                        &["?"],
+                        "false",
+                    )
+                    .into_iter()
+                    .map(|p| Mutant::from_partial(p, target, "TF")),
+                ),
+                "AD" => all_mutants.extend(
+                    tolk_patterns::remove_asserts(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "AD")),
+                ),
+                "AN" => all_mutants.extend(
+                    tolk_patterns::negate_assert_conditions(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "AN")),
+                ),
+                "TC" => all_mutants.extend(
+                    tolk_patterns::change_exit_codes(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "TC")),
+                ),
+                "MAR" => all_mutants.extend(
+                    tolk_patterns::remove_match_arms(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "MAR")),
+                ),
+                "MAS" => all_mutants.extend(
+                    tolk_patterns::swap_match_arm_bodies(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "MAS")),
+                ),
+                "MAE" => all_mutants.extend(
+                    tolk_patterns::replace_match_arm_with_else(root, source)
+                        .into_iter()
+                        .map(|p| Mutant::from_partial(p, target, "MAE")),
+                ),
                 "AS" => {
                     all_mutants.extend(
                         patterns::swap_args(
@@ -272,16 +405,26 @@ impl LanguageEngine for TolkLanguageEngine {
                     .into_iter()
                     .map(|p| Mutant::from_partial(p, target, "NR")),
                 ),
-                _ => {
-                    panic!(
-                        "Unknown mutation slug encountered in Tolk engine: {}",
-                        m.slug
-                    );
-                }
+                slug => match self.rules().iter().find(|rule| rule.mutation.slug == slug) {
+                    Some(rule) => all_mutants.extend(
+                        rule.apply(root, source)
+                            .into_iter()
+                            .map(|p| Mutant::from_partial(p, target, slug)),
+                    ),
+                    None => panic!("Unknown mutation slug encountered in Tolk engine: {slug}"),
+                },
             }
         }
 
-        all_mutants
+        let functions = tolk_patterns::function_spans(root, source);
+        languages::finish_mutants(
+            target,
+            language,
+            root,
+            &functions,
+            &self.selection,
+            all_mutants,
+        )
     }
 }
 
@@ -1,5 +1,56 @@
-use mewt::types::Mutation;
+use mewt::types::{Mutation, MutationSeverity};
 
 /// Tolk-specific mutations
 /// Most mutations are covered by COMMON_MUTATIONS in the engine
-pub const TOLK_MUTATIONS: &[Mutation] = &[];
+pub const TOLK_MUTATIONS: &[Mutation] = &[
+    Mutation {
+        slug: "RZ",
+        description: "Repeat Zero: Hardcode repeat count to 0",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "UF",
+        description: "Until False: Hardcode do-while condition to false",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "TT",
+        description: "Ternary True: Hardcode ternary condition to true",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "TF",
+        description: "Ternary False: Hardcode ternary condition to false",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "AD",
+        description: "Assert Deletion: remove an assert statement",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "AN",
+        description: "Assert Negation: negate the condition of an assert statement",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "TC",
+        description: "Throw Code: change the exit code of a throw or assert statement",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "MAR",
+        description: "Match Arm Removal: remove a pattern arm of a match",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "MAS",
+        description: "Match Arm Swap: swap the bodies of adjacent match arms",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "MAE",
+        description: "Match Arm Else: replace a match arm body with the else arm body",
+        severity: MutationSeverity::Medium,
+    },
+];
@@ -0,0 +1,180 @@
+use mewt::types::PartialMutant;
+use mewt::utils::{calculate_line_offset, is_in_comment, node_text, visit_nodes_with_cursor};
+use tree_sitter::Node;
+
+use crate::languages::patterns::{increment_operand, remove_statement, replace_node};
+use crate::languages::scope::FunctionSpan;
+use crate::languages::tolk::syntax::{fields, nodes};
+
+/// Visit every node of the given kind outside of comments
+fn visit_kind(root: Node, kind: &str, callback: &mut dyn FnMut(Node<'_>)) {
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if node.kind() == kind && !is_in_comment(&node) {
+            callback(node);
+        }
+    });
+}
+
+/// Remove `assert` statements entirely
+pub fn remove_asserts(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_kind(root, nodes::ASSERT_STATEMENT, &mut |node| {
+        mutants.push(remove_statement(&node, source));
+    });
+    mutants
+}
+
+/// Negate the condition of `assert` statements
+pub fn negate_assert_conditions(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_kind(root, nodes::ASSERT_STATEMENT, &mut |node| {
+        if let Some(condition) = node.child_by_field_name(fields::CONDITION) {
+            let new_text = format!("!({})", node_text(&condition, source));
+            mutants.push(replace_node(&condition, source, new_text));
+        }
+    });
+    mutants
+}
+
+/// Change the exit code thrown by `throw` and `assert` statements
+pub fn change_exit_codes(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    for kind in [nodes::THROW_STATEMENT, nodes::ASSERT_STATEMENT] {
+        visit_kind(root, kind, &mut |node| {
+            let Some(code) = node.child_by_field_name(fields::EXC_NO) else {
+                return;
+            };
+            // `throw (code, arg)` carries the exit code as the first tensor item
+            let code = if code.kind() == nodes::TENSOR_EXPRESSION {
+                match code.named_child(0) {
+                    Some(first) => first,
+                    None => return,
+                }
+            } else {
+                code
+            };
+            let code_text = node_text(&code, source);
+            if let Some(new_text) = increment_operand(code_text, is_simple_operand(code_text)) {
+                mutants.push(replace_node(&code, source, new_text));
+            }
+        });
+    }
+    mutants
+}
+
+/// Tolk allows binary operators without surrounding whitespace, so only a name
+/// or literal such as `ERR_AUTH` or `Errors.NotOwner` is a single operand
+fn is_simple_operand(text: &str) -> bool {
+    text.chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+/// Visit the arms of every `match`, split into the pattern arms and the optional
+/// `else` arm
+fn visit_match_arms(root: Node, callback: &mut dyn FnMut(&[Node<'_>], Option<Node<'_>>)) {
+    visit_kind(root, nodes::MATCH_BODY, &mut |body| {
+        let mut c = body.walk();
+        let (else_arms, arms): (Vec<_>, Vec<_>) = body
+            .named_children(&mut c)
+            .filter(|n| n.kind() == nodes::MATCH_ARM)
+            .partition(|arm| arm.child_by_field_name(fields::PATTERN_ELSE).is_some());
+        callback(&arms, else_arms.first().copied());
+    });
+}
+
+/// Get the body of a `match` arm: a block, a `return`, a `throw` or an expression
+fn arm_body<'tree>(arm: &Node<'tree>) -> Option<Node<'tree>> {
+    [fields::BLOCK, fields::RETURN, fields::THROW, fields::EXPR]
+        .iter()
+        .find_map(|field| arm.child_by_field_name(field))
+}
+
+/// Block bodies end an arm on their own while other bodies need a `,`, so only
+/// bodies of the same shape can stand in for each other
+fn same_shape(a: &Node, b: &Node) -> bool {
+    (a.kind() == nodes::BLOCK_STATEMENT) == (b.kind() == nodes::BLOCK_STATEMENT)
+}
+
+/// Remove a single pattern arm of a `match`
+pub fn remove_match_arms(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_match_arms(root, &mut |arms, _| {
+        for arm in arms {
+            mutants.push(replace_node(arm, source, ""));
+        }
+    });
+    mutants
+}
+
+/// Swap the bodies of adjacent pattern arms of a `match`
+pub fn swap_match_arm_bodies(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_match_arms(root, &mut |arms, _| {
+        for pair in arms.windows(2) {
+            let (Some(first), Some(second)) = (arm_body(&pair[0]), arm_body(&pair[1])) else {
+                continue;
+            };
+            let (first_text, second_text) = (node_text(&first, source), node_text(&second, source));
+            if !same_shape(&first, &second) || first_text == second_text {
+                continue;
+            }
+            let between = &source[first.end_byte()..second.start_byte()];
+            mutants.push(PartialMutant {
+                byte_offset: first.start_byte() as u32,
+                line_offset: calculate_line_offset(source, first.start_byte()),
+                old_text: source[first.start_byte()..second.end_byte()].to_string(),
+                new_text: format!("{second_text}{between}{first_text}"),
+            });
+        }
+    });
+    mutants
+}
+
+/// Replace the body of a pattern arm with the body of the `else` arm
+pub fn replace_match_arm_with_else(root: Node, source: &str) -> Vec<PartialMutant> {
+    let mut mutants = Vec::new();
+    visit_match_arms(root, &mut |arms, else_arm| {
+        let Some(else_body) = else_arm.as_ref().and_then(arm_body) else {
+            return;
+        };
+        let else_text = node_text(&else_body, source);
+        for body in arms.iter().filter_map(arm_body) {
+            if same_shape(&body, &else_body) && node_text(&body, source) != else_text {
+                mutants.push(replace_node(&body, source, else_text));
+            }
+        }
+    });
+    mutants
+}
+
+/// Find the span of every function, method and get method. Methods are named
+/// with their receiver type, as in `Point.sum`.
+pub fn function_spans(root: Node, source: &str) -> Vec<FunctionSpan> {
+    let mut spans = Vec::new();
+    let mut cursor = root.walk();
+    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
+        if !matches!(
+            node.kind(),
+            nodes::FUNCTION_DECLARATION | nodes::METHOD_DECLARATION | nodes::GET_METHOD_DECLARATION
+        ) {
+            return;
+        }
+        let Some(name) = node.child_by_field_name(fields::NAME) else {
+            return;
+        };
+        let name = node_text(&name, source);
+        let receiver = node
+            .child_by_field_name(fields::RECEIVER)
+            .and_then(|r| r.child_by_field_name(fields::RECEIVER_TYPE));
+        spans.push(FunctionSpan {
+            name: match receiver {
+                Some(receiver) => format!("{}.{name}", node_text(&receiver, source)),
+                None => name.to_string(),
+            },
+            start_byte: node.start_byte(),
+            end_byte: node.end_byte(),
+        });
+    });
+    spans
+}
@@ -1,5 +1,10 @@
 /// Node type names from tree-sitter-tolk grammar
 pub mod nodes {
+    // Declarations
+    pub const FUNCTION_DECLARATION: &str = "function_declaration";
+    pub const METHOD_DECLARATION: &str = "method_declaration";
+    pub const GET_METHOD_DECLARATION: &str = "get_method_declaration";
+
     // Statements
     pub const IF_STATEMENT: &str = "if_statement";
     pub const RETURN_STATEMENT: &str = "return_statement";
@@ -20,10 +25,14 @@ pub mod nodes {
     pub const BINARY_OPERATOR: &str = "binary_operator";
     pub const UNARY_OPERATOR: &str = "unary_operator";
     pub const BOOLEAN_LITERAL: &str = "boolean_literal";
+    pub const TERNARY_OPERATOR: &str = "ternary_operator";
+    pub const TENSOR_EXPRESSION: &str = "tensor_expression";
     pub const ASSIGNMENT: &str = "assignment";
     pub const SET_ASSIGNMENT: &str = "set_assignment";
     pub const LAMBDA_EXPRESSION: &str = "lambda_expression";
     pub const MATCH_EXPRESSION: &str = "match_expression";
+    pub const MATCH_BODY: &str = "match_body";
+    pub const MATCH_ARM: &str = "match_arm";
 }
 
 /// Field names from tree-sitter-tolk grammar
@@ -32,6 +41,7 @@ pub mod fields {
     pub const CONDITION: &str = "condition";
     pub const BODY: &str = "body";
     pub const ALTERNATIVE: &str = "alternative";
+    pub const COUNT: &str = "count";
 
     // Function call fields
     pub const FUNCTION_NAME: &str = "function_name";
@@ -43,6 +53,20 @@ pub mod fields {
     pub const LEFT: &str = "left";
     pub const RIGHT: &str = "right";
 
+    // Throw/assert fields
+    pub const EXC_NO: &str = "excNo";
+
+    // Match arm fields
+    pub const PATTERN_ELSE: &str = "pattern_else";
+    pub const BLOCK: &str = "block";
+    pub const RETURN: &str = "return";
+    pub const THROW: &str = "throw";
+
+    // Declaration fields
+    pub const NAME: &str = "name";
+    pub const RECEIVER_TYPE: &str = "receiver_type";
+    pub const RECEIVER: &str = "receiver";
+
     // Other fields
     pub const EXPR: &str = "expr";
     pub const VALUE: &str = "value";
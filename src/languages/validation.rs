@@ -0,0 +1,81 @@
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use mewt::types::{Mutant, Target};
+use mewt::utils::parse_source;
+use tree_sitter::{Language as TsLanguage, Node};
+
+use crate::languages::SlugCounts;
+use crate::languages::diagnostics::error_ranges;
+
+/// Mutants discarded since the last [`take_discarded_count`]
+static DISCARDED: AtomicUsize = AtomicUsize::new(0);
+
+/// Re-parse each mutant and drop the ones that add a syntax error, since they
+/// can only fail to compile. An error can surface far from the mutated span,
+/// such as a missing `}` at the end of the file, so every error away from the
+/// span must be one the original has at the same place, and the errors
+/// overlapping the span may not outnumber the original's there.
+pub fn discard_invalid_mutants(
+    target: &Target,
+    language: &TsLanguage,
+    original: Node,
+    mutants: Vec<Mutant>,
+) -> (Vec<Mutant>, SlugCounts) {
+    let original_errors = error_ranges(original);
+    let mut discarded = SlugCounts::new();
+    let valid = mutants
+        .into_iter()
+        .filter(|mutant| {
+            let is_valid = target.mutate(mutant).is_ok_and(|mutated| {
+                parse_source(&mutated, language).is_some_and(|tree| {
+                    adds_no_errors(mutant, &original_errors, &error_ranges(tree.root_node()))
+                })
+            });
+            if !is_valid {
+                *discarded.entry(mutant.mutation_slug.clone()).or_default() += 1;
+            }
+            is_valid
+        })
+        .collect();
+    DISCARDED.fetch_add(discarded.values().sum(), Ordering::Relaxed);
+    (valid, discarded)
+}
+
+/// Number of mutants discarded since the last call, for the summary logged
+/// after mutants are generated
+pub fn take_discarded_count() -> usize {
+    DISCARDED.swap(0, Ordering::Relaxed)
+}
+
+/// Whether the errors of a mutated source are all errors of the original: the
+/// ones away from the mutated span at the same place once shifted by the
+/// change in length, and no more of them than before overlapping the span
+fn adds_no_errors(mutant: &Mutant, original: &[Range<usize>], mutated: &[Range<usize>]) -> bool {
+    let start = mutant.byte_offset as usize;
+    let old_end = start + mutant.old_text.len();
+    let new_end = start + mutant.new_text.len();
+    let unchanged: Vec<Range<usize>> = original
+        .iter()
+        .filter_map(|error| {
+            if error.end <= start {
+                Some(error.clone())
+            } else if error.start >= old_end {
+                Some(error.start - old_end + new_end..error.end - old_end + new_end)
+            } else {
+                None
+            }
+        })
+        .collect();
+    let mut overlapping = 0;
+    for error in mutated {
+        if error.end <= start || error.start >= new_end {
+            if !unchanged.contains(error) {
+                return false;
+            }
+        } else {
+            overlapping += 1;
+        }
+    }
+    overlapping <= original.len() - unchanged.len()
+}
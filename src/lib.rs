@@ -1,4 +1,13 @@
 // Library interface for muton
 // Exposes language engines for testing and external use
 
+pub mod blueprint;
+pub mod campaign;
+pub mod cli;
+pub mod config;
+pub mod coverage;
+pub mod effectiveness;
 pub mod languages;
+pub mod results;
+pub mod skipped;
+pub mod test_output;
@@ -1,26 +1,415 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use mewt::LanguageRegistry;
-use mewt::run_main;
+use clap::Parser;
+use log::{info, warn};
+use mewt::core::cli::{PrintArgs, ResultsArgs, RunArgs};
+use mewt::core::cmds;
+use mewt::core::cmds::print::{MutantsFilters, MutationsFilters, PrintCommand};
+use mewt::core::cmds::results::ResultsFilters;
+use mewt::core::logging::init_logging;
+use mewt::types::Target;
+use mewt::types::config::{
+    CliOverrides, ResolvedTargets, config, find_nearest_config_file, init_with_overrides,
+    set_config_path, set_namespace,
+};
+use mewt::{LanguageRegistry, SqlStore};
+use muton::blueprint::BlueprintProject;
+use muton::campaign;
+use muton::cli::{Cli, Command, CoverageArgs, EffectivenessArgs};
+use muton::config::muton_config;
+use muton::coverage::{LineCoverage, mark_uncovered, report_uncovered};
+use muton::effectiveness::EffectivenessReport;
 use muton::languages;
+use muton::languages::compile::{EQUIVALENT_SKIP, UNCOMPILABLE_SKIP, mark_compile_outcomes};
+use muton::languages::scope::Selection;
+use muton::languages::{changes, diagnostics, validation};
+use muton::results;
 
-const MUTON_VERSION: &str = env!("CARGO_PKG_VERSION");
+const CONFIG_FILENAME: &str = "muton.toml";
+
+type MainResult<T> = Result<T, Box<dyn Error>>;
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Create language registry and register supported languages
+async fn main() -> MainResult<()> {
+    let cli = Cli::parse();
+
+    // Note whether `init` is about to create a fresh config in this directory
+    let start_dir = env::current_dir()?;
+    let fresh_init =
+        matches!(cli.command, Command::Init) && !start_dir.join(CONFIG_FILENAME).exists();
+
+    init_config(
+        cli.config.as_deref(),
+        CliOverrides {
+            db: cli.db.clone(),
+            log_level: cli.log_level.clone(),
+            log_color: cli.log_color.clone(),
+        },
+    )?;
+    init_logging();
+
+    let selection = cli.command.selection(&muton_config().scope());
+    let registry = Arc::new(language_registry(&selection));
+    let store = create_store().await?;
+
+    let running = Arc::new(AtomicBool::new(true));
+    let running_ctrlc = Arc::clone(&running);
+    ctrlc::set_handler(move || {
+        warn!("Received Ctrl-C, cleaning up..");
+        running_ctrlc.store(false, Ordering::SeqCst);
+    })?;
+
+    match cli.command {
+        Command::Init => {
+            cmds::execute_init().await?;
+            if fresh_init && env::current_dir()? == start_dir {
+                configure_blueprint_project(&start_dir)?;
+            }
+        }
+        Command::Run(command) => {
+            run(
+                &command.args,
+                &store,
+                Arc::clone(&running),
+                registry,
+                &selection,
+            )
+            .await?;
+            if !running.load(Ordering::SeqCst) {
+                // The campaign was interrupted
+                process::exit(2);
+            }
+        }
+        Command::Mutate(command) => {
+            let resolved = config().resolve_targets(
+                &command.args.targets,
+                command.args.ignore_targets.as_deref(),
+            )?;
+            if needs_target_checks(&selection) {
+                load_targets(&registry, &store, &resolved, None, &selection).await?;
+            }
+            let mutations = config().resolve_mutations(None);
+            cmds::execute_mutate(command.args, store.clone(), registry, resolved, mutations)
+                .await?;
+            report_discarded();
+            screen_mutants(&store, &selection).await?;
+        }
+        Command::Clean => cmds::execute_clean(store).await?,
+        Command::Results(args) => print_results(args, store, &registry).await?,
+        Command::Print { command } => print(command, store, registry).await?,
+        Command::Status(args) => {
+            let table = args.format != "json";
+            cmds::execute_status(args, store.clone(), registry).await?;
+            if table {
+                report_screened(&store, false).await?;
+            }
+        }
+        Command::Test(args) => campaign::execute_test(args, &store, running, registry).await?,
+        Command::Purge(args) => cmds::execute_purge(args, store).await?,
+        Command::Effectiveness(args) => print_effectiveness(&args, &store).await?,
+        Command::Coverage(args) => apply_coverage(&args, &store, &selection).await?,
+    }
+    Ok(())
+}
+
+/// Register the supported languages, generating only the selected mutants
+fn language_registry(selection: &Selection) -> LanguageRegistry {
     let mut registry = LanguageRegistry::new();
-    registry.register(languages::func::engine::FuncLanguageEngine::new());
-    registry.register(languages::tact::engine::TactLanguageEngine::new());
-    registry.register(languages::tolk::engine::TolkLanguageEngine::new());
-
-    // Run the shared main function
-    run_main(
-        Arc::new(registry),
-        "muton",
-        "Mutation Testing Framework for TON Blockchain Languages",
-        Some(MUTON_VERSION),
-    )
-    .await?;
+    registry.register(
+        languages::func::engine::FuncLanguageEngine::new().with_selection(selection.clone()),
+    );
+    registry.register(
+        languages::tact::engine::TactLanguageEngine::new().with_selection(selection.clone()),
+    );
+    registry.register(
+        languages::tolk::engine::TolkLanguageEngine::new().with_selection(selection.clone()),
+    );
+    registry
+}
+
+/// Fill a freshly created config with the targets and test commands of a
+/// Blueprint project, if the directory holds one
+fn configure_blueprint_project(dir: &Path) -> std::io::Result<()> {
+    let Some(project) = BlueprintProject::detect(dir) else {
+        return Ok(());
+    };
+    let path = dir.join(CONFIG_FILENAME);
+    let config = fs::read_to_string(&path)?;
+    fs::write(&path, project.apply_to_config(&config))?;
+    info!(
+        "Detected a Blueprint project: {} contract(s), {} with spec files",
+        project.contracts.len(),
+        project.specs.len()
+    );
+    Ok(())
+}
+
+/// Find the config file the way the shared runtime does, either the one given
+/// with `--config` or the nearest one up from the working directory, make its
+/// directory the working directory and apply the global CLI overrides
+fn init_config(config_path: Option<&str>, overrides: CliOverrides) -> MainResult<()> {
+    set_namespace("muton");
+    let config_path = match config_path {
+        Some(path) => {
+            let path = PathBuf::from(path).canonicalize()?;
+            if !path.is_file() {
+                return Err(format!("Config path is not a file: {}", path.display()).into());
+            }
+            Some(path)
+        }
+        None => find_nearest_config_file(),
+    };
+    if let Some(path) = config_path {
+        set_config_path(Some(path.clone()));
+        if let Some(parent) = path.parent() {
+            env::set_current_dir(parent)?;
+        }
+    }
+    init_with_overrides(&overrides);
+    Ok(())
+}
+
+/// Whether strict parse mode or `--since` needs the targets checked up front
+fn needs_target_checks(selection: &Selection) -> bool {
+    muton_config().parse().strict() || selection.since.is_some()
+}
+
+/// Load the targets, failing on parse errors in strict parse mode or on a
+/// `--since` revision they cannot be diffed against
+async fn load_targets(
+    registry: &LanguageRegistry,
+    store: &SqlStore,
+    resolved: &ResolvedTargets,
+    mutations: Option<&[String]>,
+    selection: &Selection,
+) -> MainResult<Vec<Target>> {
+    let targets = Target::load_targets(resolved, store, registry, mutations).await?;
+    if muton_config().parse().strict() {
+        diagnostics::check_strict(&targets)?;
+    }
+    if let Some(rev) = &selection.since {
+        changes::check_since(&targets, rev)?;
+    }
+    Ok(targets)
+}
+
+/// Generate and store the mutants of the given targets, or without targets
+/// pick up the stored mutants still to be tested, screen them and then test
+/// the selected ones
+async fn run(
+    args: &RunArgs,
+    store: &SqlStore,
+    running: Arc<AtomicBool>,
+    registry: Arc<LanguageRegistry>,
+    selection: &Selection,
+) -> MainResult<()> {
+    let targets = if !args.targets.is_empty() || args.ignore_targets.is_some() {
+        let resolved = config().resolve_targets(&args.targets, args.ignore_targets.as_deref())?;
+        let mutations = config().resolve_mutations(args.mutations.as_deref());
+        let targets =
+            load_targets(&registry, store, &resolved, mutations.as_deref(), selection).await?;
+        for target in &targets {
+            let Ok(mutants) = target.generate_mutants(&registry, mutations.as_deref()) else {
+                continue;
+            };
+            for mut mutant in mutants {
+                if let Some(id) = store.add_mutant(mutant.clone()).await? {
+                    mutant.id = id;
+                    info!("  Saved new mutant: {}", mutant.display(target));
+                }
+            }
+        }
+        report_discarded();
+        Some(targets)
+    } else {
+        None
+    };
+
+    // Screened mutants get an outcome before the first test runs
+    screen_mutants(store, selection).await?;
+
+    let targets = match targets {
+        Some(targets) => targets,
+        None => campaign::targets_to_test(store).await?,
+    };
+    if targets.is_empty() {
+        info!("No mutants to test found in database");
+        return Ok(());
+    }
+    campaign::execute_run(args, store, running, registry, targets, selection).await?;
+    Ok(())
+}
+
+/// Log how many generated mutants were discarded because they add syntax
+/// errors, after the summary of the mutants saved
+fn report_discarded() {
+    let discarded = validation::take_discarded_count();
+    if discarded > 0 {
+        info!("Discarded {discarded} mutant(s) that add syntax errors");
+    }
+}
+
+/// Record outcomes for the selected untested mutants that need no test run:
+/// those on lines the configured LCOV data shows no test executes, and those
+/// the configured compiler rejects or compiles to the original code
+async fn screen_mutants(store: &SqlStore, selection: &Selection) -> MainResult<()> {
+    let lcov = muton_config().coverage().lcov().to_vec();
+    if !lcov.is_empty() {
+        let marked = mark_uncovered(store, &load_coverage(&lcov), selection).await?;
+        info!("Marked {marked} mutant(s) on lines no test executes as Skipped");
+    }
+    if muton_config().compile().is_configured() || muton_config().equivalence().is_configured() {
+        let marks = mark_compile_outcomes(
+            store,
+            &muton_config().compile(),
+            &muton_config().equivalence(),
+            selection,
+        )
+        .await?;
+        info!(
+            "Marked {} uncompilable and {} equivalent mutant(s) as Skipped",
+            marks.uncompilable, marks.equivalent
+        );
+    }
+    Ok(())
+}
+
+/// Open the configured database, creating it like the shared runtime does
+async fn create_store() -> MainResult<SqlStore> {
+    let db = config().db();
+    if !Path::new(&db).exists() {
+        fs::File::create(&db)?;
+    }
+    Ok(SqlStore::new(format!("sqlite:{db}")).await?)
+}
+
+fn load_coverage(lcov: &[String]) -> LineCoverage {
+    let (coverage, unreadable) = LineCoverage::from_files(lcov);
+    for error in unreadable {
+        warn!("Ignoring coverage file {error}");
+    }
+    coverage
+}
+
+/// Print results as the shared runtime does, except that the JSON format
+/// carries muton's fields, such as the enclosing function, and the table ends
+/// with the mutants skipped before any test ran
+async fn print_results(
+    args: ResultsArgs,
+    store: SqlStore,
+    registry: &LanguageRegistry,
+) -> MainResult<()> {
+    if args.format == "json" {
+        let data = results::load(&store, &args, registry).await?;
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&results::annotate(data))?
+        );
+        return Ok(());
+    }
+    let table = args.id.is_none() && !matches!(args.format.as_str(), "sarif" | "ids");
+    let filters = ResultsFilters {
+        target: args.target,
+        verbose: args.verbose,
+        id: args.id,
+        all: args.all,
+        status: args.status,
+        language: args.language,
+        mutation_types: args.mutation_types,
+        severity: args.severity,
+        line: args.line,
+        format: args.format,
+    };
+    cmds::execute_results(store.clone(), filters, registry).await?;
+    if table {
+        report_screened(&store, true).await?;
+    }
+    Ok(())
+}
+
+/// Log a section for each kind of mutant skipped before any test ran, after
+/// the output of `muton status` or `muton results`
+async fn report_screened(store: &SqlStore, list: bool) -> MainResult<()> {
+    report_uncovered(store, list).await?;
+    UNCOMPILABLE_SKIP.report(store, list).await?;
+    EQUIVALENT_SKIP.report(store, list).await?;
+    Ok(())
+}
+
+async fn print(
+    command: PrintArgs,
+    store: SqlStore,
+    registry: Arc<LanguageRegistry>,
+) -> MainResult<()> {
+    let (command, store) = match command {
+        PrintArgs::Mutations(args) => (
+            PrintCommand::Mutations(MutationsFilters {
+                language: args.language,
+                format: args.format,
+            }),
+            None,
+        ),
+        PrintArgs::Targets(args) => (PrintCommand::Targets(args.format), Some(store)),
+        PrintArgs::Mutant(args) => (PrintCommand::Mutant(args.id), Some(store)),
+        PrintArgs::Mutants(args) => (
+            PrintCommand::Mutants(MutantsFilters {
+                target: args.target,
+                line: args.line,
+                mutation_types: args.mutation_types,
+                severity: args.severity,
+                tested: args.tested,
+                untested: args.untested,
+                format: args.format,
+            }),
+            Some(store),
+        ),
+        PrintArgs::Config(args) => (PrintCommand::Config(args.format), None),
+    };
+    cmds::execute_print(command, store, registry).await?;
+    Ok(())
+}
+
+/// Print which tests killed each mutant, parsed from Jest JSON or TAP test
+/// output, as a per-test table or as JSON
+async fn print_effectiveness(args: &EffectivenessArgs, store: &SqlStore) -> MainResult<()> {
+    let report = EffectivenessReport::load(store).await?;
+    if args.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print!("{}", report.render_table());
+    }
+    Ok(())
+}
+
+/// Mark untested mutants on lines that no test executes as `Skipped`, using
+/// the LCOV files given with `--lcov` or listed under `[coverage] lcov`, or
+/// with `--list` print the mutants marked so far
+async fn apply_coverage(
+    args: &CoverageArgs,
+    store: &SqlStore,
+    selection: &Selection,
+) -> MainResult<()> {
+    if args.list {
+        if report_uncovered(store, true).await? == 0 {
+            info!("No mutants were skipped for lack of coverage");
+        }
+        return Ok(());
+    }
+    let lcov = if args.lcov.is_empty() {
+        muton_config().coverage().lcov().to_vec()
+    } else {
+        args.lcov.clone()
+    };
+    if lcov.is_empty() {
+        return Err("no LCOV files; pass --lcov or set [coverage] lcov in muton.toml".into());
+    }
+    let marked = mark_uncovered(store, &load_coverage(&lcov), selection).await?;
+    info!("Marked {marked} mutant(s) on lines no test executes as Skipped");
     Ok(())
 }
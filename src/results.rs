@@ -0,0 +1,132 @@
+//! `muton results --format json`: the results the shared runtime reports, with
+//! fields muton adds about the TON code and the test output of each mutant.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::str::FromStr;
+
+use mewt::core::cli::ResultsArgs;
+use mewt::core::utils::parse_csv;
+use mewt::types::{AppResult, Mutant, MutationSeverity, Outcome, Status, Target};
+use mewt::{LanguageRegistry, SqlStore};
+use serde::Serialize;
+
+use crate::coverage::is_not_covered;
+use crate::languages;
+use crate::languages::compile::{is_equivalent, is_uncompilable};
+use crate::languages::scope::{FunctionSpan, enclosing_function};
+use crate::test_output::{TestCase, parse_test_output};
+
+/// One result of `muton results --format json`
+#[derive(Debug, Serialize)]
+pub struct AnnotatedResult {
+    pub mutant: Mutant,
+    pub target: Target,
+    pub outcome: Outcome,
+    /// The function, receiver or method enclosing the mutant, or `None` for
+    /// top-level code
+    pub function: Option<String>,
+    /// The test cases parsed from the outcome's Jest JSON or TAP output, or
+    /// `None` if the output is in neither format
+    pub tests: Option<Vec<TestCase>>,
+    /// Whether the mutant was skipped because no test executes its lines
+    pub not_covered: bool,
+    /// Whether the mutant was skipped because the compiler rejects it
+    pub uncompilable: bool,
+    /// Whether the mutant was skipped because it compiles to the original code
+    pub equivalent: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AnnotatedResults {
+    pub results: Vec<AnnotatedResult>,
+}
+
+/// Select the outcomes `muton results` reports, applying its filters the way
+/// the shared runtime does: with no filter, only uncaught mutants unless
+/// `--all` or `--verbose` is given
+pub async fn load(
+    store: &SqlStore,
+    args: &ResultsArgs,
+    registry: &LanguageRegistry,
+) -> AppResult<Vec<(Mutant, Target, Outcome)>> {
+    if let Some(id) = args.id {
+        let Ok(mutant) = store.get_mutant(id).await else {
+            return Ok(Vec::new());
+        };
+        let target = store.get_target(mutant.target_id).await?;
+        return Ok(match store.get_outcome(mutant.id).await? {
+            Some(outcome) => vec![(mutant, target, outcome)],
+            None => Vec::new(),
+        });
+    }
+
+    let filtered = args.target.is_some()
+        || args.status.is_some()
+        || args.language.is_some()
+        || args.mutation_types.is_some()
+        || args.line.is_some();
+    let mut results = if filtered {
+        store
+            .get_outcomes_filtered(
+                args.target.clone(),
+                args.status
+                    .as_deref()
+                    .and_then(|s| Status::from_str(s).ok())
+                    .map(|s| s.to_string()),
+                args.language.clone(),
+                parse_csv::<String>(args.mutation_types.as_deref()),
+                args.line,
+            )
+            .await?
+    } else {
+        let mut results = Vec::new();
+        for target in Target::filter_by_path_or_config(store, None).await? {
+            let mut mutants = store.get_mutants(target.id).await?;
+            mutants.sort_by_key(|m| m.byte_offset);
+            for mutant in mutants {
+                if let Some(outcome) = store.get_outcome(mutant.id).await?
+                    && (args.all || args.verbose || outcome.status == Status::Uncaught)
+                {
+                    results.push((mutant, target.clone(), outcome));
+                }
+            }
+        }
+        results
+    };
+
+    if let Some(severities) = parse_csv::<MutationSeverity>(args.severity.as_deref()) {
+        results.retain(|(mutant, target, _)| {
+            registry
+                .get_mutation(&target.language, &mutant.mutation_slug)
+                .is_some_and(|mutation| severities.contains(&mutation.severity))
+        });
+    }
+    Ok(results)
+}
+
+/// Add muton's fields to each result
+pub fn annotate(results: Vec<(Mutant, Target, Outcome)>) -> AnnotatedResults {
+    let mut functions: HashMap<i64, Vec<FunctionSpan>> = HashMap::new();
+    let results = results
+        .into_iter()
+        .map(|(mutant, target, outcome)| {
+            let spans = match functions.entry(target.id) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(languages::function_spans(&target)),
+            };
+            AnnotatedResult {
+                function: enclosing_function(spans, mutant.byte_offset as usize)
+                    .map(|f| f.name.clone()),
+                tests: parse_test_output(&outcome.output),
+                not_covered: is_not_covered(&outcome),
+                uncompilable: is_uncompilable(&outcome),
+                equivalent: is_equivalent(&outcome),
+                mutant,
+                target,
+                outcome,
+            }
+        })
+        .collect();
+    AnnotatedResults { results }
+}
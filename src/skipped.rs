@@ -0,0 +1,104 @@
+//! Mutants that get a `Skipped` outcome before any test runs, told apart from
+//! the runner's own skips by the prefix of the outcome's output.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use log::info;
+use mewt::SqlStore;
+use mewt::types::{Mutant, Outcome, Status, StoreResult, Target};
+
+use crate::languages;
+use crate::languages::scope::Selection;
+
+/// Mutants without an outcome that the selection picks out, grouped by target,
+/// for the checks that skip mutants before any test runs
+pub async fn untested_mutants(
+    store: &SqlStore,
+    selection: &Selection,
+) -> StoreResult<Vec<(Target, Vec<Mutant>)>> {
+    let mut untested: BTreeMap<i64, Vec<Mutant>> = BTreeMap::new();
+    for mutant in store.get_mutants_without_outcomes().await? {
+        untested.entry(mutant.target_id).or_default().push(mutant);
+    }
+    let mut selected = Vec::new();
+    for (target_id, mutants) in untested {
+        let target = store.get_target(target_id).await?;
+        let mutants = if selection.is_everything() {
+            mutants
+        } else {
+            selection.filter(&target, &languages::function_spans(&target), mutants)
+        };
+        if !mutants.is_empty() {
+            selected.push((target, mutants));
+        }
+    }
+    Ok(selected)
+}
+
+/// A reason to skip mutants without a test run, and how `muton status` and
+/// `muton results` report the mutants skipped for it
+#[derive(Debug, Clone, Copy)]
+pub struct SkipReason {
+    /// Prefix of the output stored with the skipped mutants
+    pub prefix: &'static str,
+    /// Title of the section listing them
+    pub title: &'static str,
+    /// Ends the section's closing "N skipped mutant(s) ..." line
+    pub summary: &'static str,
+}
+
+impl SkipReason {
+    /// Whether an outcome records a mutant skipped for this reason
+    pub fn matches(&self, outcome: &Outcome) -> bool {
+        outcome.status == Status::Skipped && outcome.output.starts_with(self.prefix)
+    }
+
+    /// Mutants skipped for this reason, grouped by target
+    pub async fn mutants(&self, store: &SqlStore) -> StoreResult<Vec<(Target, Vec<Mutant>)>> {
+        let mut skipped = Vec::new();
+        for target in store.get_all_targets().await? {
+            let ids: BTreeSet<i64> = store
+                .get_outcomes(target.id)
+                .await?
+                .into_iter()
+                .filter(|o| self.matches(o))
+                .map(|o| o.mutant_id)
+                .collect();
+            if ids.is_empty() {
+                continue;
+            }
+            let mutants = store
+                .get_mutants(target.id)
+                .await?
+                .into_iter()
+                .filter(|m| ids.contains(&m.id))
+                .collect();
+            skipped.push((target, mutants));
+        }
+        Ok(skipped)
+    }
+
+    /// Log a section after the output of `muton status` or `muton results`:
+    /// how many mutants of each target were skipped for this reason, and with
+    /// `list` the mutants themselves. Returns the number of mutants.
+    pub async fn report(&self, store: &SqlStore, list: bool) -> StoreResult<usize> {
+        let skipped = self.mutants(store).await?;
+        let total = skipped.iter().map(|(_, mutants)| mutants.len()).sum();
+        if total == 0 {
+            return Ok(0);
+        }
+        info!("");
+        info!("{}:", self.title);
+        info!("{}", "=".repeat(self.title.len() + 1));
+        for (target, mutants) in &skipped {
+            info!("Target: {}: {} mutant(s)", target.display(), mutants.len());
+            if list {
+                for mutant in mutants {
+                    info!("  {}", mutant.display(target));
+                }
+            }
+        }
+        info!("{total} skipped mutant(s) {}", self.summary);
+        Ok(total)
+    }
+}
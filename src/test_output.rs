@@ -0,0 +1,194 @@
+//! Parsers for test runner output stored with each outcome, to find which tests
+//! failed against a mutant. Jest `--json` and TAP output are recognized.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TestCase {
+    pub name: String,
+    pub passed: bool,
+    /// First line of the failure message, for failed tests
+    pub message: Option<String>,
+}
+
+/// Parse the test cases out of an outcome's output, trying Jest JSON first and
+/// then TAP. Returns `None` if neither format is found.
+pub fn parse_test_output(output: &str) -> Option<Vec<TestCase>> {
+    let stdout = stdout_section(output);
+    parse_jest_json(stdout).or_else(|| parse_tap(stdout))
+}
+
+/// The stdout part of output captured as `STDOUT:\n...\n\nSTDERR:\n...`
+fn stdout_section(output: &str) -> &str {
+    let stdout = output.strip_prefix("STDOUT:\n").unwrap_or(output);
+    match stdout.rfind("\nSTDERR:\n") {
+        Some(end) => &stdout[..end],
+        None => stdout,
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JestReport {
+    test_results: Vec<JestSuite>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JestSuite {
+    #[serde(default)]
+    assertion_results: Vec<JestAssertion>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JestAssertion {
+    full_name: String,
+    status: String,
+    #[serde(default)]
+    failure_messages: Vec<String>,
+}
+
+/// Parse the report printed by `jest --json`, which is a single line of stdout
+pub fn parse_jest_json(stdout: &str) -> Option<Vec<TestCase>> {
+    let report = stdout
+        .lines()
+        .filter(|line| line.trim_start().starts_with('{') && line.contains("\"testResults\""))
+        .find_map(|line| serde_json::from_str::<JestReport>(line.trim()).ok())?;
+    let cases = report
+        .test_results
+        .into_iter()
+        .flat_map(|suite| suite.assertion_results)
+        .filter(|a| a.status == "passed" || a.status == "failed")
+        .map(|a| TestCase {
+            passed: a.status == "passed",
+            message: a
+                .failure_messages
+                .first()
+                .and_then(|m| first_line(&strip_ansi(m))),
+            name: a.full_name,
+        })
+        .collect();
+    Some(cases)
+}
+
+/// Parse TAP `ok`/`not ok` lines, taking failure messages from the `message`
+/// key of the YAML diagnostics that follow a failed test. Tests with a `TODO`
+/// or `SKIP` directive are left out, like Jest's pending and todo tests.
+pub fn parse_tap(stdout: &str) -> Option<Vec<TestCase>> {
+    let mut cases: Vec<TestCase> = Vec::new();
+    let mut in_diagnostics = false;
+    // Whether the last test line was left out, so its diagnostics are too
+    let mut left_out = false;
+    for line in stdout.lines() {
+        let trimmed = line.trim();
+        if in_diagnostics {
+            if trimmed == "..." {
+                in_diagnostics = false;
+            } else if let Some(message) = trimmed.strip_prefix("message:")
+                && !left_out
+                && let Some(case) = cases
+                    .last_mut()
+                    .filter(|c| !c.passed && c.message.is_none())
+            {
+                case.message = first_line(message.trim().trim_matches(['\'', '"']));
+            }
+            continue;
+        }
+        if trimmed == "---" {
+            in_diagnostics = true;
+        } else if let Some((case, directive)) = parse_tap_line(trimmed) {
+            left_out = is_todo_or_skip(directive);
+            if !left_out {
+                cases.push(case);
+            }
+        }
+    }
+    (!cases.is_empty()).then_some(cases)
+}
+
+/// Whether a TAP directive marks a test as not expected to pass or not run
+fn is_todo_or_skip(directive: Option<&str>) -> bool {
+    directive.is_some_and(|d| {
+        let keyword = d.trim_start().get(..4).unwrap_or_default();
+        keyword.eq_ignore_ascii_case("todo") || keyword.eq_ignore_ascii_case("skip")
+    })
+}
+
+/// Parse `ok 1 - name` or `not ok 2 - name # directive` into the test case and
+/// the directive, if any
+fn parse_tap_line(line: &str) -> Option<(TestCase, Option<&str>)> {
+    let (passed, rest) = match line.strip_prefix("not ok") {
+        Some(rest) => (false, rest),
+        None => (true, line.strip_prefix("ok")?),
+    };
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    let rest = rest.trim_start_matches(|c: char| c.is_ascii_digit() || c == ' ');
+    let (description, directive) = match rest.split_once(" # ") {
+        Some((description, directive)) => (description, Some(directive)),
+        None => (rest, None),
+    };
+    let name = description.trim_start_matches('-').trim();
+    let case = TestCase {
+        name: name.to_string(),
+        passed,
+        message: None,
+    };
+    Some((case, directive))
+}
+
+/// The file a test command such as `jest --json --outputFile=report.json`
+/// writes its report to, instead of printing it
+pub fn output_file(cmd: &str) -> Option<&str> {
+    let mut words = cmd.split_whitespace();
+    while let Some(word) = words.next() {
+        let path = match word.strip_prefix("--outputFile") {
+            Some("") => words.next()?,
+            Some(path) => match path.strip_prefix('=') {
+                Some(path) => path,
+                None => continue,
+            },
+            None => continue,
+        };
+        return Some(path.trim_matches(['\'', '"']));
+    }
+    None
+}
+
+/// Make a test command that writes its report with `--outputFile` print the
+/// report after it runs, keeping its exit status, so the report is stored with
+/// each outcome like printed output. Other commands are returned as they are.
+pub fn print_output_file(cmd: &str) -> String {
+    let Some(path) = output_file(cmd) else {
+        return cmd.to_string();
+    };
+    let path = format!("'{}'", path.replace('\'', r"'\''"));
+    format!("rm -f {path}; ({cmd}); status=$?; echo; cat {path} 2>/dev/null; echo; exit $status")
+}
+
+fn first_line(text: &str) -> Option<String> {
+    text.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
+/// Remove terminal color codes, which Jest includes in failure messages
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -0,0 +1,137 @@
+use std::fs;
+use std::path::Path;
+
+use mewt::types::config::is_path_excluded;
+use muton::blueprint::BlueprintProject;
+
+fn write(root: &Path, path: &str, contents: &str) {
+    let path = root.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+fn counter_project() -> tempfile::TempDir {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    write(
+        root,
+        "wrappers/Counter.compile.ts",
+        "export const compile: CompilerConfig = {\n    lang: 'func',\n    targets: ['contracts/imports/stdlib.fc', 'contracts/counter.fc'],\n};\n",
+    );
+    write(
+        root,
+        "wrappers/Jetton.compile.ts",
+        "export const compile: CompilerConfig = {\n    lang: 'tact',\n    target: \"contracts/jetton.tact\",\n};\n",
+    );
+    write(
+        root,
+        "tests/Counter.spec.ts",
+        "beforeAll(async () => { code = await compile('Counter'); });\n",
+    );
+    write(
+        root,
+        "tests/Jetton.spec.ts",
+        "import { Jetton } from '../build/Jetton/tact_Jetton';\n",
+    );
+    write(
+        root,
+        "tests/Other.spec.ts",
+        "import { Other } from '../wrappers/Other';\n",
+    );
+    tmp
+}
+
+#[test]
+fn non_blueprint_directory_is_not_detected() {
+    let tmp = tempfile::tempdir().unwrap();
+    write(tmp.path(), "contracts/counter.fc", "");
+    assert_eq!(BlueprintProject::detect(tmp.path()), None);
+}
+
+#[test]
+fn wrappers_map_contracts_to_spec_files() {
+    let tmp = counter_project();
+    let project = BlueprintProject::detect(tmp.path()).unwrap();
+
+    assert_eq!(
+        project.contracts,
+        [
+            "contracts/counter.fc",
+            "contracts/imports/stdlib.fc",
+            "contracts/jetton.tact"
+        ]
+    );
+    assert_eq!(
+        project.specs["contracts/counter.fc"],
+        ["tests/Counter.spec.ts"]
+    );
+    assert_eq!(
+        project.specs["contracts/jetton.tact"],
+        ["tests/Jetton.spec.ts"]
+    );
+    assert_eq!(
+        project.include_globs(),
+        ["contracts/**/*.fc", "contracts/**/*.tact"]
+    );
+}
+
+#[test]
+fn blueprint_config_alone_is_detected() {
+    let tmp = tempfile::tempdir().unwrap();
+    write(
+        tmp.path(),
+        "blueprint.config.ts",
+        "export const config = {};\n",
+    );
+    let project = BlueprintProject::detect(tmp.path()).unwrap();
+    assert!(project.contracts.is_empty());
+}
+
+#[test]
+fn generated_config_gets_targets_and_per_target_tests() {
+    let tmp = counter_project();
+    let project = BlueprintProject::detect(tmp.path()).unwrap();
+    let config =
+        project.apply_to_config("[targets]\n# include = []\n\n[test]\n# cmd = \"cargo test\"\n");
+
+    let parsed: toml::Table = toml::from_str(&config).unwrap();
+    assert_eq!(
+        parsed["targets"]["include"].as_array().unwrap().len(),
+        2,
+        "{config}"
+    );
+    assert_eq!(parsed["test"]["cmd"].as_str(), Some("npx blueprint test"));
+    let rules = parsed["test"]["per_target"].as_array().unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0]["glob"].as_str(), Some("contracts/counter.fc"));
+    assert_eq!(
+        rules[0]["cmd"].as_str(),
+        Some("npx jest tests/Counter.spec.ts")
+    );
+}
+
+#[test]
+fn generated_config_ignores_only_library_sources() {
+    let tmp = counter_project();
+    let project = BlueprintProject::detect(tmp.path()).unwrap();
+    let config = project.apply_to_config("[targets]\n");
+
+    let parsed: toml::Table = toml::from_str(&config).unwrap();
+    let ignore: Vec<String> = parsed["targets"]["ignore"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|pattern| pattern.as_str().unwrap().to_string())
+        .collect();
+    assert!(is_path_excluded(
+        Path::new("contracts/imports/stdlib.fc"),
+        &ignore
+    ));
+    for kept in [
+        "contracts/counter.fc",
+        "contracts/jetton_builder.fc",
+        "contracts/build_helpers.tact",
+    ] {
+        assert!(!is_path_excluded(Path::new(kept), &ignore), "{kept}");
+    }
+}
@@ -0,0 +1,140 @@
+#[path = "utils.rs"]
+mod utils;
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use mewt::LanguageRegistry;
+use mewt::core::cli::RunArgs;
+use mewt::types::{Mutant, Target};
+use muton::campaign::execute_run;
+use muton::languages::function_spans;
+use muton::languages::scope::{Selection, enclosing_function};
+use muton::languages::tact::engine::TactLanguageEngine;
+
+const SOURCE: &str = r#"contract Counter {
+    value: Int = 0;
+
+    receive("increment") {
+        self.value = self.value + 1;
+    }
+
+    fun greet(name: String): String {
+        return "Hello, " + name;
+    }
+}
+"#;
+
+/// Store the mutants of `SOURCE`, written to `dir`, as `muton mutate` does,
+/// then run a campaign over the selected ones with `test_cmd`. Returns each
+/// stored mutant with whether it got an outcome, and whether the run succeeded.
+async fn run_selected(
+    dir: &Path,
+    selection: Selection,
+    test_cmd: &str,
+) -> (Target, Vec<(Mutant, bool)>, bool) {
+    let store = utils::create_store(dir).await;
+    let path = dir.join("counter.tact");
+    fs::write(&path, SOURCE).unwrap();
+
+    let mut registry = LanguageRegistry::new();
+    registry.register(TactLanguageEngine::new());
+    let registry = Arc::new(registry);
+
+    let mut target = utils::new_target(path, SOURCE, "Tact");
+    target.id = store.add_target(target.clone()).await.unwrap();
+    let mut mutants = Vec::new();
+    for mut mutant in target.generate_mutants(&registry, None).unwrap() {
+        mutant.id = store.add_mutant(mutant.clone()).await.unwrap().unwrap();
+        mutants.push(mutant);
+    }
+
+    let args = RunArgs {
+        targets: Vec::new(),
+        ignore_targets: None,
+        mutations: None,
+        test_cmd: Some(test_cmd.to_string()),
+        test_timeout: None,
+        comprehensive: true,
+        verbose: false,
+    };
+    let running = Arc::new(AtomicBool::new(true));
+    let succeeded = execute_run(
+        &args,
+        &store,
+        running,
+        registry,
+        vec![target.clone()],
+        &selection,
+    )
+    .await
+    .is_ok();
+
+    let mut tested = Vec::new();
+    for mutant in mutants {
+        let outcome = store.get_outcome(mutant.id).await.unwrap();
+        tested.push((mutant, outcome.is_some()));
+    }
+    (target, tested, succeeded)
+}
+
+#[tokio::test]
+async fn run_tests_only_mutants_in_the_selected_functions() {
+    let tmp = tempfile::tempdir().unwrap();
+    let selection = Selection {
+        functions: vec!["greet".to_string()],
+        since: None,
+    };
+    let (target, mutants, succeeded) = run_selected(tmp.path(), selection, "true").await;
+    assert!(succeeded);
+
+    let spans = function_spans(&target);
+    for (mutant, tested) in &mutants {
+        let in_greet = enclosing_function(&spans, mutant.byte_offset as usize)
+            .is_some_and(|f| f.name == "greet");
+        assert_eq!(*tested, in_greet, "{mutant:?}");
+    }
+    assert!(mutants.iter().any(|(_, tested)| *tested));
+    assert!(mutants.iter().any(|(_, tested)| !*tested));
+}
+
+#[tokio::test]
+async fn run_tests_only_mutants_on_changed_lines() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    utils::git(root, &["init", "-q"]);
+    fs::write(root.join("counter.tact"), SOURCE.replace("Hello", "Hi")).unwrap();
+    utils::git(root, &["add", "counter.tact"]);
+    utils::git(root, &["commit", "-q", "-m", "init"]);
+
+    let selection = Selection {
+        functions: Vec::new(),
+        since: Some("HEAD".to_string()),
+    };
+    let (_, mutants, succeeded) = run_selected(root, selection, "true").await;
+    assert!(succeeded);
+
+    for (mutant, tested) in &mutants {
+        let (first, last) = mutant.get_lines();
+        assert_eq!(*tested, (first..=last).contains(&9), "{mutant:?}");
+    }
+    assert!(mutants.iter().any(|(_, tested)| *tested));
+    assert!(mutants.iter().any(|(_, tested)| !*tested));
+}
+
+#[tokio::test]
+async fn run_leaves_the_source_intact_when_the_test_command_cannot_start() {
+    let tmp = tempfile::tempdir().unwrap();
+    let selection = Selection {
+        functions: vec!["greet".to_string()],
+        since: None,
+    };
+    let (target, mutants, succeeded) =
+        run_selected(tmp.path(), selection, "/nonexistent/muton-test-runner").await;
+
+    assert!(!succeeded);
+    assert_eq!(fs::read_to_string(&target.path).unwrap(), SOURCE);
+    assert!(mutants.iter().all(|(_, tested)| !*tested));
+}
@@ -0,0 +1,83 @@
+#[path = "utils.rs"]
+mod utils;
+
+use std::collections::BTreeSet;
+use std::fs;
+
+use muton::languages::changes::{ChangedLines, changed_lines, check_since, parse_unified_diff};
+
+const DIFF: &str = "diff --git a/wallet.tact b/wallet.tact
+index 1111111..2222222 100644
+--- a/wallet.tact
++++ b/wallet.tact
+@@ -3 +3 @@ contract Wallet {
+-    receive(\"a\") {}
++    receive(\"b\") {}
+@@ -10,0 +11,2 @@ contract Wallet {
++        self.seqno += 1;
++        self.seqno += 2;
+@@ -20,3 +22,0 @@ fun helper() {
+-        a();
+-        b();
+-        c();
+";
+
+#[test]
+fn hunk_headers_give_changed_lines() {
+    let lines = parse_unified_diff(DIFF);
+    assert_eq!(lines, BTreeSet::from([3, 11, 12, 22, 23]));
+}
+
+#[test]
+fn changed_lines_intersect_mutant_spans() {
+    let changed = ChangedLines::Lines(parse_unified_diff(DIFF));
+    assert!(changed.intersects(3, 3));
+    assert!(changed.intersects(8, 11));
+    assert!(!changed.intersects(4, 10));
+    assert!(ChangedLines::All.intersects(100, 100));
+}
+
+#[test]
+fn working_tree_changes_are_diffed_against_a_revision() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    utils::git(root, &["init", "-q"]);
+    fs::write(root.join("counter.fc"), "a\nb\nc\nd\n").unwrap();
+    utils::git(root, &["add", "counter.fc"]);
+    utils::git(root, &["commit", "-q", "-m", "init"]);
+
+    fs::write(root.join("counter.fc"), "a\nB\nc\nd\ne\n").unwrap();
+    fs::write(root.join("new.fc"), "x\n").unwrap();
+
+    assert_eq!(
+        changed_lines(&root.join("counter.fc"), "HEAD"),
+        Ok(ChangedLines::Lines(BTreeSet::from([2, 5])))
+    );
+    assert_eq!(
+        changed_lines(&root.join("new.fc"), "HEAD"),
+        Ok(ChangedLines::All)
+    );
+    assert!(changed_lines(&root.join("counter.fc"), "no-such-rev").is_err());
+}
+
+#[test]
+fn files_outside_a_repository_cannot_be_diffed() {
+    let tmp = tempfile::tempdir().unwrap();
+    fs::write(tmp.path().join("counter.fc"), "a\n").unwrap();
+    assert!(changed_lines(&tmp.path().join("counter.fc"), "HEAD").is_err());
+}
+
+#[test]
+fn a_revision_that_cannot_be_diffed_fails_the_check() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    utils::git(root, &["init", "-q"]);
+    fs::write(root.join("counter.fc"), "a\n").unwrap();
+    utils::git(root, &["add", "counter.fc"]);
+    utils::git(root, &["commit", "-q", "-m", "init"]);
+    let target = utils::new_target(root.join("counter.fc"), "a\n", "FunC");
+
+    assert_eq!(check_since(std::slice::from_ref(&target), "HEAD"), Ok(()));
+    let error = check_since(&[target], "no-such-rev").unwrap_err();
+    assert!(error.contains("cannot diff against no-such-rev"), "{error}");
+}
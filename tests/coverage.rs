@@ -0,0 +1,184 @@
+#[path = "utils.rs"]
+mod utils;
+
+use std::path::Path;
+
+use chrono::Utc;
+use mewt::types::{Mutant, Outcome, Status};
+use muton::coverage::{
+    LineCoverage, NOT_COVERED, mark_uncovered, report_uncovered, uncovered_mutants,
+};
+use muton::languages::scope::Selection;
+
+const LCOV: &str = "TN:
+SF:./contracts/wallet.tact
+DA:3,4
+DA:5,0
+DA:6,0
+end_of_record
+SF:/home/ci/project/contracts/counter.fc
+DA:2,0
+end_of_record
+";
+
+#[test]
+fn spans_with_only_unexecuted_lines_are_uncovered() {
+    let coverage = LineCoverage::parse_lcov(LCOV);
+    let wallet = Path::new("contracts/wallet.tact");
+
+    assert!(!coverage.is_uncovered(wallet, 3, 3));
+    assert!(coverage.is_uncovered(wallet, 5, 6));
+    assert!(!coverage.is_uncovered(wallet, 3, 5));
+    // Lines without instrumentation are not known to be uncovered
+    assert!(!coverage.is_uncovered(wallet, 7, 9));
+    assert!(coverage.is_uncovered(wallet, 4, 5));
+}
+
+#[test]
+fn files_without_coverage_data_are_never_uncovered() {
+    let coverage = LineCoverage::parse_lcov(LCOV);
+    assert!(!coverage.is_uncovered(Path::new("contracts/other.tact"), 1, 100));
+}
+
+#[test]
+fn absolute_lcov_paths_match_relative_targets() {
+    let coverage = LineCoverage::parse_lcov(LCOV);
+    assert!(coverage.is_uncovered(Path::new("contracts/counter.fc"), 2, 2));
+}
+
+#[test]
+fn hits_from_several_tracefiles_are_summed() {
+    let mut coverage = LineCoverage::parse_lcov(LCOV);
+    coverage.add_lcov("SF:contracts/wallet.tact\nDA:5,1\nend_of_record\n");
+    let wallet = Path::new("contracts/wallet.tact");
+    assert!(!coverage.is_uncovered(wallet, 5, 5));
+    assert!(coverage.is_uncovered(wallet, 6, 6));
+}
+
+fn mutant(target_id: i64, line_offset: u32, old_text: &str) -> Mutant {
+    Mutant {
+        id: 0,
+        target_id,
+        byte_offset: 0,
+        line_offset,
+        old_text: old_text.to_string(),
+        new_text: String::new(),
+        mutation_slug: "ER".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn uncovered_mutants_get_a_skipped_outcome() {
+    let tmp = tempfile::tempdir().unwrap();
+    let store = utils::create_store(tmp.path()).await;
+
+    let target = utils::new_target("contracts/wallet.tact", "contract C {}\n", "Tact");
+    let target_id = store.add_target(target).await.unwrap();
+    let covered = store
+        .add_mutant(mutant(target_id, 2, "a"))
+        .await
+        .unwrap()
+        .unwrap();
+    let uncovered = store
+        .add_mutant(mutant(target_id, 4, "b\nc"))
+        .await
+        .unwrap()
+        .unwrap();
+
+    let coverage = LineCoverage::parse_lcov(LCOV);
+    let everything = Selection::default();
+    assert_eq!(
+        mark_uncovered(&store, &coverage, &everything)
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        mark_uncovered(&store, &coverage, &everything)
+            .await
+            .unwrap(),
+        0
+    );
+
+    assert!(store.get_outcome(covered).await.unwrap().is_none());
+    let outcome = store.get_outcome(uncovered).await.unwrap().unwrap();
+    assert_eq!(outcome.status, Status::Skipped);
+    assert!(outcome.output.starts_with(NOT_COVERED));
+}
+
+#[tokio::test]
+async fn uncovered_mutants_are_listed_apart_from_other_skips() {
+    let tmp = tempfile::tempdir().unwrap();
+    let store = utils::create_store(tmp.path()).await;
+
+    let target = utils::new_target("contracts/wallet.tact", "contract C {}\n", "Tact");
+    let target_id = store.add_target(target).await.unwrap();
+    let severity_skip = store
+        .add_mutant(mutant(target_id, 2, "a"))
+        .await
+        .unwrap()
+        .unwrap();
+    let uncovered = store
+        .add_mutant(mutant(target_id, 4, "b"))
+        .await
+        .unwrap()
+        .unwrap();
+    store
+        .add_outcome(Outcome {
+            mutant_id: severity_skip,
+            status: Status::Skipped,
+            output: "Skipped due to uncaught higher severity mutation on the same line".to_string(),
+            time: Utc::now(),
+            duration_ms: 0,
+        })
+        .await
+        .unwrap();
+    mark_uncovered(
+        &store,
+        &LineCoverage::parse_lcov(LCOV),
+        &Selection::default(),
+    )
+    .await
+    .unwrap();
+
+    let listed = uncovered_mutants(&store).await.unwrap();
+    assert_eq!(listed.len(), 1);
+    let ids: Vec<i64> = listed[0].1.iter().map(|m| m.id).collect();
+    assert_eq!(ids, vec![uncovered]);
+    assert_eq!(report_uncovered(&store, true).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn only_selected_mutants_are_marked() {
+    let tmp = tempfile::tempdir().unwrap();
+    let store = utils::create_store(tmp.path()).await;
+
+    let text = "contract C {\n    x: Int;\n    fun a() { self.x = 0; }\n    fun b() {\n        self.x = 1; }\n    fun c() { self.x = 2; }\n}\n".to_string();
+    let at = |target_id: i64, needle: &str| {
+        let byte_offset = text.find(needle).unwrap();
+        Mutant {
+            byte_offset: byte_offset as u32,
+            ..mutant(
+                target_id,
+                text[..byte_offset].matches('\n').count() as u32,
+                needle,
+            )
+        }
+    };
+    let target = utils::new_target("contracts/wallet.tact", &text, "Tact");
+    let target_id = store.add_target(target).await.unwrap();
+    let in_b = store.add_mutant(at(target_id, "1")).await.unwrap().unwrap();
+    let in_c = store.add_mutant(at(target_id, "2")).await.unwrap().unwrap();
+
+    let selection = Selection {
+        functions: vec!["c".to_string()],
+        since: None,
+    };
+    let coverage = LineCoverage::parse_lcov(LCOV);
+    assert_eq!(
+        mark_uncovered(&store, &coverage, &selection).await.unwrap(),
+        1
+    );
+    assert!(store.get_outcome(in_b).await.unwrap().is_none());
+    assert!(store.get_outcome(in_c).await.unwrap().is_some());
+}
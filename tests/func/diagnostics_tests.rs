@@ -0,0 +1,53 @@
+use muton::languages::diagnostics::{ParseError, check_strict, parse_errors, report_parse_errors};
+
+use crate::func::parser_tests::parse_func;
+use crate::utils;
+
+#[test]
+fn clean_source_has_no_parse_errors() {
+    let source = "() test() { return 1; }";
+    let tree = parse_func(source);
+    assert!(parse_errors(tree.root_node(), source).is_empty());
+}
+
+#[test]
+fn parse_errors_are_located_by_line_and_column() {
+    let source = "() test() {\n  int x = ;\n  return x;\n}\n";
+    let tree = parse_func(source);
+    assert_eq!(
+        parse_errors(tree.root_node(), source),
+        [ParseError {
+            line: 2,
+            column: 10,
+            message: "missing `identifier`".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn strict_mode_fails_on_parse_errors() {
+    let source = "() test() {\n  int x = ;\n}\n";
+    let fixture = utils::func_target(source);
+    let tree = parse_func(source);
+    let errors = parse_errors(tree.root_node(), source);
+
+    assert!(report_parse_errors(fixture.target(), &[]).is_ok());
+    let failure = report_parse_errors(fixture.target(), &errors)
+        .expect_err("strict mode should fail on parse errors");
+    assert!(failure.contains("strict parse mode"), "{failure}");
+}
+
+#[test]
+fn strict_check_parses_targets_before_mutation() {
+    let clean = utils::func_target("() test() {\n  int x = 1;\n}\n");
+    let broken = utils::func_target("() test() {\n  int x = ;\n}\n");
+
+    assert!(check_strict(&[clean.target().clone()]).is_ok());
+    let failure = check_strict(&[clean.target().clone(), broken.target().clone()])
+        .expect_err("strict check should fail on the broken target");
+    assert!(failure.contains("strict parse mode"), "{failure}");
+    assert!(
+        failure.contains(&broken.path().display().to_string()),
+        "{failure}"
+    );
+}
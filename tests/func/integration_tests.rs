@@ -1,11 +1,23 @@
+use std::path::Path;
+
 use crate::conformance;
 use crate::utils;
-use mewt::LanguageEngine;
+use mewt::types::config::ResolvedTargets;
 use mewt::types::{Mutant, Target};
+use mewt::{LanguageEngine, LanguageRegistry};
 use muton::languages::func::engine::FuncLanguageEngine;
+use muton::languages::tact::engine::TactLanguageEngine;
+use muton::languages::tolk::engine::TolkLanguageEngine;
 
 pub(crate) fn create_test_target(content: &str) -> (tempfile::TempDir, Target) {
-    utils::target_fixture_for_extension("FunC", "fc", content).into_parts()
+    create_test_target_with_extension(content, "fc")
+}
+
+pub(crate) fn create_test_target_with_extension(
+    content: &str,
+    extension: &str,
+) -> (tempfile::TempDir, Target) {
+    utils::target_fixture_for_extension("FunC", extension, content).into_parts()
 }
 
 pub(crate) fn mutants_for_slug(source: &str, slug: &str) -> Vec<Mutant> {
@@ -103,6 +115,96 @@ fn func_example_file_generates_mutants() {
     assert_ne!(mutated, target.text);
 }
 
+#[test]
+fn engine_reports_fc_and_func_extensions() {
+    assert_eq!(FuncLanguageEngine::new().extensions(), &["fc", "func"]);
+}
+
+#[test]
+fn registry_resolves_func_extensions_alongside_other_languages() {
+    let mut registry = LanguageRegistry::new();
+    registry.register(FuncLanguageEngine::new());
+    registry.register(TactLanguageEngine::new());
+    registry.register(TolkLanguageEngine::new());
+
+    let cases = [
+        ("contracts/wallet.fc", Some("FunC")),
+        ("contracts/imports/stdlib.func", Some("FunC")),
+        ("contracts/LEGACY.FUNC", Some("FunC")),
+        ("contracts/jetton.tact", Some("Tact")),
+        ("contracts/nft.tolk", Some("Tolk")),
+        ("contracts/README.md", None),
+    ];
+    for (path, expected) in cases {
+        let language = registry
+            .language_from_path(Path::new(path))
+            .map(|engine| engine.name());
+        assert_eq!(language, expected, "unexpected language for {path}");
+    }
+}
+
+#[test]
+fn func_extension_yields_same_mutants_as_fc() {
+    let source = conformance::read_example_source("tests/func/examples/hello-world.fc");
+    let (_fc_tmp, fc_target) = create_test_target_with_extension(&source, "fc");
+    let (_func_tmp, func_target) = create_test_target_with_extension(&source, "func");
+
+    let mut registry = LanguageRegistry::new();
+    registry.register(FuncLanguageEngine::new());
+
+    // Resolve the engine from the file path, as target loading does
+    let mutants_for = |target: &Target| -> Vec<(String, u32, String, String)> {
+        registry
+            .language_from_path(&target.path)
+            .unwrap_or_else(|| panic!("no engine for {}", target.path.display()))
+            .mutate(target)
+            .into_iter()
+            .map(|m| (m.mutation_slug, m.byte_offset, m.old_text, m.new_text))
+            .collect()
+    };
+
+    let fc_mutants = mutants_for(&fc_target);
+    let func_mutants = mutants_for(&func_target);
+
+    assert!(
+        !fc_mutants.is_empty(),
+        "FunC example should generate mutants"
+    );
+    assert_eq!(fc_mutants, func_mutants);
+}
+
+#[tokio::test]
+async fn func_extension_targets_are_discovered_and_mutated() {
+    let tmp = tempfile::tempdir().unwrap();
+    let store = utils::create_store(tmp.path()).await;
+    let mut registry = LanguageRegistry::new();
+    registry.register(FuncLanguageEngine::new());
+
+    // Discover targets from the examples directory, as `muton mutate` does
+    let resolved = ResolvedTargets {
+        include: vec!["tests/func/examples".to_string()],
+        ignore: Vec::new(),
+    };
+    let targets = Target::load_targets(&resolved, &store, &registry, None)
+        .await
+        .expect("examples should load");
+    let counter = targets
+        .iter()
+        .find(|t| t.path.ends_with("counter.func"))
+        .expect("the .func example should be discovered");
+    assert_eq!(counter.language, "FunC");
+    assert!(targets.iter().any(|t| t.path.ends_with("hello-world.fc")));
+
+    let mutants = counter
+        .generate_mutants(&registry, None)
+        .expect("the .func example should be mutated");
+    assert!(mutants.iter().any(|m| m.mutation_slug == "BER"));
+    for mutant in mutants {
+        assert!(store.add_mutant(mutant).await.unwrap().is_some());
+    }
+    assert!(!store.get_mutants(counter.id).await.unwrap().is_empty());
+}
+
 #[test]
 fn func_shared_slugs_presence() {
     // FunC sample with if and a call with 2 args
@@ -1,3 +1,6 @@
+mod diagnostics_tests;
 mod integration_tests;
 mod mutations;
 mod parser_tests;
+mod suppression_tests;
+mod validation_tests;
@@ -0,0 +1,17 @@
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn amr_removes_accept_message_calls() {
+    let source = r#"
+() recv_external(slice in_msg) impure {
+    throw_unless(35, check_signature(hash, signature, public_key));
+    accept_message();
+    ;; accept_message();
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "AMR");
+    assert_eq!(mutants.len(), 1);
+    assert_eq!(mutants[0].old_text, "accept_message();");
+    assert_eq!(mutants[0].new_text, "");
+}
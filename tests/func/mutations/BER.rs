@@ -0,0 +1,36 @@
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn ber_removes_bounced_early_return() {
+    let source = r#"
+() recv_internal(int my_balance, int msg_value, cell in_msg_full, slice in_msg_body) impure {
+    slice cs = in_msg_full.begin_parse();
+    int flags = cs~load_uint(4);
+    if (flags & 1) { ;; ignore all bounced messages
+        return ();
+    }
+    if (1 & flags) { return (); }
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "BER");
+    assert_eq!(mutants.len(), 2);
+    assert!(mutants[0].old_text.starts_with("if (flags & 1) {"));
+    assert_eq!(mutants[0].new_text, "");
+    assert_eq!(mutants[1].old_text, "if (1 & flags) { return (); }");
+}
+
+#[test]
+fn ber_skips_other_conditionals() {
+    let source = r#"
+() recv_internal(int my_balance, int msg_value, cell in_msg_full, slice in_msg_body) impure {
+    int flags = cs~load_uint(4);
+    ifnot (flags & 1) { return (); }
+    if (flags & 2) { return (); }
+    if (flags & 1) { on_bounce(in_msg_body); return (); }
+    if (flags & 1) { return (); } else { accept(); }
+}
+"#;
+
+    assert!(mutants_for_slug(source, "BER").is_empty());
+}
@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn bw_changes_store_and_load_widths() {
+    let source = r#"
+() save(int seqno, slice owner) impure {
+    set_data(begin_cell().store_uint(seqno, 32).store_slice(owner).end_cell());
+}
+
+(int, int) load() {
+    slice ds = get_data().begin_parse();
+    int seqno = ds~load_uint(64);
+    int id = load_int(ds, 267);
+    return (seqno, id);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "BW");
+    let rewrites: HashSet<_> = mutants
+        .iter()
+        .map(|m| format!("{} -> {}", m.old_text, m.new_text))
+        .collect();
+    let expected: HashSet<_> = [
+        "32 -> 16",
+        "32 -> 64",
+        "64 -> 32",
+        "64 -> 128",
+        "267 -> 256",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    assert_eq!(rewrites, expected);
+}
+
+#[test]
+fn bw_skips_non_literal_widths() {
+    let source = r#"
+() save(int value, int bits) impure {
+    set_data(begin_cell().store_uint(value, bits).end_cell());
+}
+"#;
+
+    assert!(mutants_for_slug(source, "BW").is_empty());
+}
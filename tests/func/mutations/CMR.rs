@@ -0,0 +1,19 @@
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn cmr_removes_commit_checkpoints() {
+    let source = r#"
+() recv_external(slice in_msg) impure {
+    accept_message();
+    stored_seqno += 1;
+    save_data(stored_seqno);
+    commit();
+    send_raw_message(msg, mode);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "CMR");
+    assert_eq!(mutants.len(), 1);
+    assert_eq!(mutants[0].old_text, "commit();");
+    assert_eq!(mutants[0].new_text, "");
+}
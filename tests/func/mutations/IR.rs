@@ -0,0 +1,28 @@
+use crate::func::integration_tests::{create_test_target, mutants_for_slug};
+
+#[test]
+fn ir_drops_impure_specifiers() {
+    let source = r#"
+() check_owner(slice sender) impure inline {
+    throw_unless(401, equal_slices(sender, owner));
+}
+
+() log_event() impure {
+}
+
+int pure_helper() inline {
+    return 1;
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "IR");
+    let rewrites: Vec<_> = mutants
+        .iter()
+        .map(|m| (m.old_text.as_str(), m.new_text.as_str()))
+        .collect();
+    assert_eq!(rewrites, [("impure inline", "inline"), ("impure", "")]);
+
+    let (_tmp, target) = create_test_target(source);
+    let mutated = target.mutate(&mutants[0]).unwrap();
+    assert!(mutated.contains("() check_owner(slice sender) inline {"));
+}
@@ -0,0 +1,26 @@
+use std::collections::HashSet;
+
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn lis_swaps_load_signedness() {
+    let source = r#"
+(int, int) load() {
+    slice ds = get_data().begin_parse();
+    int seqno = ds~load_uint(32);
+    int delta = ds.preload_int(16);
+    return (seqno, delta);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "LIS");
+    let rewrites: HashSet<_> = mutants
+        .iter()
+        .map(|m| format!("{} -> {}", m.old_text, m.new_text))
+        .collect();
+    let expected: HashSet<_> = ["load_uint -> load_int", "preload_int -> preload_uint"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    assert_eq!(rewrites, expected);
+}
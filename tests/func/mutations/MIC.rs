@@ -0,0 +1,53 @@
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn mic_changes_explicit_method_ids() {
+    let source = r#"
+int seqno() method_id {
+    return 0;
+}
+
+int get_public_key() method_id(85143) {
+    return 0;
+}
+
+int get_version() method_id(0x10) {
+    return 1;
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "MIC");
+    let rewrites: Vec<_> = mutants
+        .iter()
+        .map(|m| (m.old_text.as_str(), m.new_text.as_str()))
+        .collect();
+    assert_eq!(rewrites, [("85143", "85144"), ("0x10", "17")]);
+}
+
+#[test]
+fn mic_skips_ids_declared_elsewhere_and_never_overflows() {
+    let source = r#"
+int get_a() method_id(10) {
+    return 0;
+}
+
+int get_b() method_id(11) {
+    return 0;
+}
+
+int get_c() method_id(12) {
+    return 0;
+}
+
+int get_max() method_id(18446744073709551615) {
+    return 0;
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "MIC");
+    let rewrites: Vec<_> = mutants
+        .iter()
+        .map(|m| (m.old_text.as_str(), m.new_text.as_str()))
+        .collect();
+    assert_eq!(rewrites, [("10", "13"), ("11", "13"), ("12", "13")]);
+}
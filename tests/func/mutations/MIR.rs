@@ -0,0 +1,23 @@
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn mir_drops_method_id_specifiers() {
+    let source = r#"
+int seqno() method_id {
+    return get_data().begin_parse().preload_uint(32);
+}
+
+int get_public_key() method_id(85143) {
+    return 0;
+}
+
+int helper() inline {
+    return 0;
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "MIR");
+    let removed: Vec<_> = mutants.iter().map(|m| m.old_text.as_str()).collect();
+    assert_eq!(removed, ["method_id", "method_id(85143)"]);
+    assert!(mutants.iter().all(|m| m.new_text.is_empty()));
+}
@@ -0,0 +1,50 @@
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn opr_replaces_op_with_other_constants() {
+    let source = r#"
+const op::transfer = 0x0f8a7ea5;
+const int op::burn = 0x595f07bc;
+const op_mint = 21;
+const error::unknown_op = 0xffff;
+
+() recv_internal(int my_balance, int msg_value, cell in_msg_full, slice in_msg_body) impure {
+    int op = in_msg_body~load_uint(32);
+    if (op == op::transfer) {
+        return ();
+    }
+    throw_if(error::unknown_op, op != op_mint);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "OPR");
+    let replacements: Vec<(&str, &str)> = mutants
+        .iter()
+        .map(|m| (m.old_text.as_str(), m.new_text.as_str()))
+        .collect();
+    assert_eq!(
+        replacements,
+        vec![
+            ("op::transfer", "op::burn"),
+            ("op::transfer", "op_mint"),
+            ("op_mint", "op::transfer"),
+            ("op_mint", "op::burn"),
+        ]
+    );
+}
+
+#[test]
+fn opr_needs_two_op_constants() {
+    let source = r#"
+const op::transfer = 0x0f8a7ea5;
+
+() recv_internal(int my_balance, int msg_value, cell in_msg_full, slice in_msg_body) impure {
+    int op = in_msg_body~load_uint(32);
+    if (op == op::transfer) {
+        return ();
+    }
+}
+"#;
+
+    assert!(mutants_for_slug(source, "OPR").is_empty());
+}
@@ -0,0 +1,17 @@
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn rrr_removes_raw_reserve_calls() {
+    let source = r#"
+() recv_internal(int my_balance, int msg_value, cell in_msg_full, slice in_msg_body) impure {
+    raw_reserve(min_tons_for_storage, 0);
+    int reserved = raw_reserve(1, 0);
+    send_raw_message(msg, 128);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "RRR");
+    assert_eq!(mutants.len(), 1);
+    assert_eq!(mutants[0].old_text, "raw_reserve(min_tons_for_storage, 0);");
+    assert_eq!(mutants[0].new_text, "");
+}
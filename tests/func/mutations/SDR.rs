@@ -0,0 +1,29 @@
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn sdr_removes_set_data_calls_and_method_chains() {
+    let source = r#"
+() save_data(int seqno) impure inline {
+    set_data(begin_cell().store_uint(seqno, 32).end_cell());
+}
+
+() save_counter(int counter) impure inline {
+    begin_cell().store_uint(counter, 64).end_cell().set_data();
+}
+
+cell current() inline {
+    return get_data();
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "SDR");
+    let removed: Vec<_> = mutants.iter().map(|m| m.old_text.as_str()).collect();
+    assert_eq!(
+        removed,
+        [
+            "set_data(begin_cell().store_uint(seqno, 32).end_cell());",
+            "begin_cell().store_uint(counter, 64).end_cell().set_data();"
+        ]
+    );
+    assert!(mutants.iter().all(|m| m.new_text.is_empty()));
+}
@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn smb_swaps_base_mode_and_keeps_flags() {
+    let source = r#"
+() refund(cell msg) impure {
+    send_raw_message(msg, 64 + 1);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "SMB");
+    let new_texts: HashSet<_> = mutants
+        .iter()
+        .map(|m| m.new_text.trim().to_string())
+        .collect();
+    let expected: HashSet<_> = ["1", "128 + 1"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(new_texts, expected);
+}
+
+#[test]
+fn smb_skips_non_literal_modes() {
+    let source = r#"
+() forward(cell msg, int mode) impure {
+    send_raw_message(msg, mode);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "SMB");
+    assert!(
+        mutants.is_empty(),
+        "expected no SMB mutants for a non-literal mode"
+    );
+}
+
+#[test]
+fn smb_reads_uppercase_hex_modes() {
+    let source = r#"
+() refund(cell msg) impure {
+    send_raw_message(msg, 0X40 | 1);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "SMB");
+    let new_texts: HashSet<_> = mutants
+        .iter()
+        .map(|m| m.new_text.trim().to_string())
+        .collect();
+    let expected: HashSet<_> = ["1", "128 + 1"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(new_texts, expected);
+}
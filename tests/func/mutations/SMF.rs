@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn smf_toggles_literal_send_mode_flags() {
+    let source = r#"
+() refund(cell msg) impure {
+    send_raw_message(msg, 64);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "SMF");
+    let new_texts: HashSet<_> = mutants
+        .iter()
+        .map(|m| m.new_text.trim().to_string())
+        .collect();
+    for expected in ["64 + 1", "64 + 2", "64 + 16", "64 + 32"] {
+        assert!(
+            new_texts.contains(expected),
+            "missing SMF mode `{expected}`; new_texts: {new_texts:?}"
+        );
+    }
+}
+
+#[test]
+fn smf_clears_flags_already_set_in_method_calls() {
+    let source = r#"
+() drain(cell msg) impure {
+    msg.send_raw_message(128 + 32);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "SMF");
+    assert!(
+        mutants.iter().all(|m| m.old_text == "128 + 32"),
+        "expected SMF to target only the mode argument"
+    );
+    assert!(
+        mutants.iter().any(|m| m.new_text == "128"),
+        "expected SMF to clear the destroy-if-zero flag"
+    );
+}
+
+#[test]
+fn smf_xors_non_literal_modes() {
+    let source = r#"
+() forward(cell msg, int mode) impure {
+    send_raw_message(msg, mode);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "SMF");
+    assert!(
+        mutants.iter().any(|m| m.new_text == "mode ^ 1"),
+        "expected SMF to flip bits of a non-literal mode with xor"
+    );
+}
+
+#[test]
+fn smf_combines_or_ed_flags_bitwise() {
+    let source = r#"
+() refund(cell msg) impure {
+    send_raw_message(msg, 64 | 1 | 1);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "SMF");
+    let new_texts: HashSet<_> = mutants
+        .iter()
+        .map(|m| m.new_text.trim().to_string())
+        .collect();
+    let expected: HashSet<_> = ["64", "64 + 2 + 1", "64 + 16 + 1", "64 + 32 + 1"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    assert_eq!(new_texts, expected, "`1 | 1` should set the flag once");
+}
@@ -0,0 +1,29 @@
+use std::collections::HashSet;
+
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn tc_changes_throw_error_codes() {
+    let source = r#"
+() recv_internal(slice sender, int amount) impure {
+    throw_unless(401, equal_slices(sender, owner));
+    throw_if(error::low_amount, amount < 100);
+    throw_arg_unless(amount, 0xffff, amount > 0);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "TC");
+    let rewrites: HashSet<_> = mutants
+        .iter()
+        .map(|m| format!("{} -> {}", m.old_text, m.new_text))
+        .collect();
+    let expected: HashSet<_> = [
+        "401 -> 402",
+        "error::low_amount -> error::low_amount + 1",
+        "0xffff -> 65536",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    assert_eq!(rewrites, expected);
+}
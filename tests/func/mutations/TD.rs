@@ -0,0 +1,24 @@
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn td_removes_conditional_throw_statements() {
+    let source = r#"
+() recv_internal(slice sender, int amount) impure {
+    throw_unless(401, equal_slices(sender, owner));
+    throw_if(402, amount < 100);
+    int ok = throw_if(403, amount > 0);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "TD");
+    let removed: Vec<_> = mutants.iter().map(|m| m.old_text.as_str()).collect();
+    assert_eq!(
+        removed,
+        [
+            "throw_unless(401, equal_slices(sender, owner));",
+            "throw_if(402, amount < 100);"
+        ],
+        "expected TD to delete only standalone throw statements"
+    );
+    assert!(mutants.iter().all(|m| m.new_text.is_empty()));
+}
@@ -0,0 +1,25 @@
+use std::collections::HashSet;
+
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn tn_replaces_conditions_with_never_throwing_constants() {
+    let source = r#"
+() recv_internal(slice sender, int amount) impure {
+    throw_unless(401, equal_slices(sender, owner));
+    throw_if(402, amount < 100);
+    throw_if(403, 0);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "TN");
+    let rewrites: HashSet<_> = mutants
+        .iter()
+        .map(|m| format!("{} -> {}", m.old_text, m.new_text))
+        .collect();
+    let expected: HashSet<_> = ["equal_slices(sender, owner) -> -1", "amount < 100 -> 0"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    assert_eq!(rewrites, expected);
+}
@@ -0,0 +1,29 @@
+use std::collections::HashSet;
+
+use crate::func::integration_tests::mutants_for_slug;
+
+#[test]
+fn ts_swaps_throw_if_and_throw_unless() {
+    let source = r#"
+() recv_internal(slice sender, int amount) impure {
+    throw_unless(401, equal_slices(sender, owner));
+    throw_if(402, amount < 100);
+    throw_arg_if(amount, 403, amount > 1000);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "TS");
+    let rewrites: HashSet<_> = mutants
+        .iter()
+        .map(|m| format!("{} -> {}", m.old_text, m.new_text))
+        .collect();
+    let expected: HashSet<_> = [
+        "throw_unless -> throw_if",
+        "throw_if -> throw_unless",
+        "throw_arg_if -> throw_arg_unless",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    assert_eq!(rewrites, expected);
+}
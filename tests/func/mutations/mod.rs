@@ -2,16 +2,24 @@
 
 #[path = "AAOS.rs"]
 mod aaos;
+#[path = "AMR.rs"]
+mod amr;
 #[path = "AOS.rs"]
 mod aos;
 #[path = "AS.rs"]
 mod r#as;
 #[path = "BAOS.rs"]
 mod baos;
+#[path = "BER.rs"]
+mod ber;
 #[path = "BL.rs"]
 mod bl;
 #[path = "BOS.rs"]
 mod bos;
+#[path = "BW.rs"]
+mod bw;
+#[path = "CMR.rs"]
+mod cmr;
 #[path = "COS.rs"]
 mod cos;
 #[path = "CR.rs"]
@@ -28,30 +36,56 @@ mod r#if;
 mod inf;
 #[path = "INT.rs"]
 mod int;
+#[path = "IR.rs"]
+mod ir;
 #[path = "IT.rs"]
 mod it;
 #[path = "LC.rs"]
 mod lc;
+#[path = "LIS.rs"]
+mod lis;
 #[path = "LOS.rs"]
 mod los;
 #[path = "MAOS.rs"]
 mod maos;
+#[path = "MIC.rs"]
+mod mic;
+#[path = "MIR.rs"]
+mod mir;
 #[path = "MOS.rs"]
 mod mos;
 #[path = "NR.rs"]
 mod nr;
+#[path = "OPR.rs"]
+mod opr;
+#[path = "RRR.rs"]
+mod rrr;
 #[path = "RZ.rs"]
 mod rz;
 #[path = "SAOS.rs"]
 mod saos;
 #[path = "SC.rs"]
 mod sc;
+#[path = "SDR.rs"]
+mod sdr;
 #[path = "SI.rs"]
 mod si;
+#[path = "SMB.rs"]
+mod smb;
+#[path = "SMF.rs"]
+mod smf;
 #[path = "SOS.rs"]
 mod sos;
 #[path = "SU.rs"]
 mod su;
+#[path = "TC.rs"]
+mod tc;
+#[path = "TD.rs"]
+mod td;
+#[path = "TN.rs"]
+mod tn;
+#[path = "TS.rs"]
+mod ts;
 #[path = "UF.rs"]
 mod uf;
 #[path = "WF.rs"]
@@ -1,6 +1,7 @@
 use std::sync::OnceLock;
 
 use mewt::utils::parse_source;
+use muton::languages::func::patterns::function_spans;
 use tree_sitter::Language as TsLanguage;
 
 static FUNC_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
@@ -9,11 +10,11 @@ unsafe extern "C" {
     fn tree_sitter_func() -> *const tree_sitter::ffi::TSLanguage;
 }
 
-fn func_language() -> &'static TsLanguage {
+pub(crate) fn func_language() -> &'static TsLanguage {
     FUNC_LANGUAGE.get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_func()) })
 }
 
-fn parse_func(source: &str) -> tree_sitter::Tree {
+pub(crate) fn parse_func(source: &str) -> tree_sitter::Tree {
     parse_source(source, func_language()).expect("FunC parser returned None")
 }
 
@@ -27,3 +28,17 @@ fn parses_minimal_function() {
     let mut cursor = root.walk();
     assert!(root.named_children(&mut cursor).count() > 0);
 }
+
+#[test]
+fn function_spans_cover_each_function_definition() {
+    let source = "int foo(int x) inline { return x; }\n() recv_internal(slice body) impure { }\n";
+    let tree = parse_func(source);
+    let spans = function_spans(tree.root_node(), source);
+
+    let names: Vec<_> = spans.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, ["foo", "recv_internal"]);
+    assert_eq!(
+        &source[spans[0].start_byte..spans[0].end_byte],
+        "int foo(int x) inline { return x; }"
+    );
+}
@@ -0,0 +1,72 @@
+use crate::func::integration_tests::create_test_target;
+use mewt::LanguageEngine;
+use muton::languages::func::engine::FuncLanguageEngine;
+
+fn mutated_lines(source: &str, slug: &str) -> Vec<u32> {
+    let (_tmp, target) = create_test_target(source);
+    let mut lines: Vec<u32> = FuncLanguageEngine::new()
+        .mutate(&target)
+        .into_iter()
+        .filter(|m| m.mutation_slug == slug)
+        .map(|m| m.line_offset)
+        .collect();
+    lines.dedup();
+    lines
+}
+
+#[test]
+fn ignore_next_line_suppresses_all_slugs_on_the_next_line() {
+    let source = r#"() main(int x) impure {
+  ;; muton-ignore-next-line
+  throw_unless(401, x > 0);
+  throw_unless(402, x < 10);
+}
+"#;
+
+    assert_eq!(mutated_lines(source, "TD"), [3]);
+    assert_eq!(mutated_lines(source, "TS"), [3]);
+}
+
+#[test]
+fn block_comment_range_suppresses_a_whole_function() {
+    let source = r#"{- muton-ignore-start -}
+() helper(int x) impure {
+  throw_if(401, x > 0);
+}
+{- muton-ignore-end -}
+
+() main(int x) impure {
+  throw_if(402, x > 0);
+}
+"#;
+
+    assert_eq!(mutated_lines(source, "TD"), [7]);
+}
+
+#[test]
+fn trailing_slug_list_suppresses_only_listed_slugs() {
+    let source = r#"() main(int x) impure {
+  throw_if(401, x > 0); ;; muton-ignore: TD, TS
+}
+"#;
+
+    assert!(mutated_lines(source, "TD").is_empty());
+    assert!(mutated_lines(source, "TS").is_empty());
+    assert_eq!(mutated_lines(source, "TN"), [1]);
+}
+
+#[test]
+fn ignore_function_suppresses_the_next_function() {
+    let source = r#";; muton-ignore-function
+() helper(int x) impure {
+  throw_if(401, x > 0);
+  throw_if(402, x < 0);
+}
+
+() main(int x) impure {
+  throw_if(403, x > 0);
+}
+"#;
+
+    assert_eq!(mutated_lines(source, "TD"), [7]);
+}
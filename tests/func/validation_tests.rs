@@ -0,0 +1,121 @@
+use mewt::types::Mutant;
+use muton::languages::validation::discard_invalid_mutants;
+
+use crate::func::parser_tests::{func_language, parse_func};
+use crate::utils;
+
+#[test]
+fn mutants_that_add_parse_errors_are_discarded() {
+    let source = "() test(int x) {\n  x = x % 3;\n}\n";
+    let fixture = utils::func_target(source);
+    let tree = parse_func(source);
+    let mutant = |slug: &str, new_text: &str| Mutant {
+        id: 0,
+        target_id: 0,
+        byte_offset: source.find('%').unwrap() as u32,
+        line_offset: 1,
+        old_text: "%".to_string(),
+        new_text: new_text.to_string(),
+        mutation_slug: slug.to_string(),
+    };
+
+    let (kept, discarded) = discard_invalid_mutants(
+        fixture.target(),
+        func_language(),
+        tree.root_node(),
+        vec![
+            mutant("MOS", "/"),
+            mutant("MOS", "% %"),
+            mutant("AOS", "+ +"),
+        ],
+    );
+
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0].new_text, "/");
+    assert_eq!(
+        discarded.into_iter().collect::<Vec<_>>(),
+        [("AOS".to_string(), 1), ("MOS".to_string(), 1)]
+    );
+}
+
+#[test]
+fn errors_already_in_the_target_do_not_discard_a_mutant() {
+    let source = "() broken() {\n  int y = ;\n}\n() test(int x) {\n  x = x % 3;\n}\n";
+    let fixture = utils::func_target(source);
+    let tree = parse_func(source);
+    let mutant = |new_text: &str| Mutant {
+        id: 0,
+        target_id: 0,
+        byte_offset: source.find('%').unwrap() as u32,
+        line_offset: 4,
+        old_text: "%".to_string(),
+        new_text: new_text.to_string(),
+        mutation_slug: "MOS".to_string(),
+    };
+
+    let (kept, discarded) = discard_invalid_mutants(
+        fixture.target(),
+        func_language(),
+        tree.root_node(),
+        vec![mutant("/"), mutant("% %")],
+    );
+
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0].new_text, "/");
+    assert_eq!(discarded.get("MOS"), Some(&1));
+}
+
+#[test]
+fn errors_away_from_the_mutated_span_discard_a_mutant() {
+    let source = "() test(int x) {\n  x = x % 3;\n}\n() other() {\n  return ();\n}\n";
+    let fixture = utils::func_target(source);
+    let tree = parse_func(source);
+    // The unclosed block only shows up as an error in the next function
+    let mutant = Mutant {
+        id: 0,
+        target_id: 0,
+        byte_offset: source.find('%').unwrap() as u32,
+        line_offset: 1,
+        old_text: "%".to_string(),
+        new_text: "% 3; {".to_string(),
+        mutation_slug: "MOS".to_string(),
+    };
+
+    let (kept, discarded) = discard_invalid_mutants(
+        fixture.target(),
+        func_language(),
+        tree.root_node(),
+        vec![mutant],
+    );
+
+    assert!(kept.is_empty());
+    assert_eq!(discarded.get("MOS"), Some(&1));
+}
+
+#[test]
+fn fixing_one_error_does_not_make_up_for_a_new_one() {
+    let source = "() test(int x) {\n  x = x % % 3;\n}\n() other() {\n  return ();\n}\n";
+    let fixture = utils::func_target(source);
+    let tree = parse_func(source);
+    // Removes the stray `%` but leaves a block open, which shows up as an
+    // error in the next function
+    let mutant = Mutant {
+        id: 0,
+        target_id: 0,
+        byte_offset: source.find("% %").unwrap() as u32,
+        line_offset: 1,
+        old_text: "% %".to_string(),
+        new_text: "% 3; {".to_string(),
+        mutation_slug: "MOS".to_string(),
+    };
+
+    let (kept, discarded) = discard_invalid_mutants(
+        fixture.target(),
+        func_language(),
+        tree.root_node(),
+        vec![mutant],
+    );
+
+    assert!(kept.is_empty());
+    assert_eq!(discarded.get("MOS"), Some(&1));
+}
@@ -0,0 +1,71 @@
+#[path = "utils.rs"]
+mod utils;
+
+use chrono::Utc;
+use mewt::types::{Mutant, Outcome, Status};
+use muton::results::annotate;
+use serde_json::json;
+
+const SOURCE: &str = "const int fee = 1;\n() recv_internal(int x) impure {\n  x = x + fee;\n}\n";
+
+#[test]
+fn results_name_the_enclosing_function_and_parsed_tests() {
+    let target = utils::new_target("contracts/wallet.fc", SOURCE, "FunC");
+    let result = |id: i64, old_text: &str, status: Status, output: &str| {
+        let mutant = Mutant {
+            id,
+            target_id: target.id,
+            byte_offset: SOURCE.find(old_text).unwrap() as u32,
+            line_offset: 0,
+            old_text: old_text.to_string(),
+            new_text: String::new(),
+            mutation_slug: "ER".to_string(),
+        };
+        let outcome = Outcome {
+            mutant_id: id,
+            status,
+            output: output.to_string(),
+            time: Utc::now(),
+            duration_ms: 0,
+        };
+        (mutant, target.clone(), outcome)
+    };
+    let tap = "TAP version 13\nok 1 - deploys\nnot ok 2 - charges fee\n  ---\n  message: 'expected 2'\n  ...\n1..2\n";
+
+    let document = serde_json::to_value(annotate(vec![
+        result(
+            1,
+            "1",
+            Status::Skipped,
+            "Not covered: no test executes lines 1-1",
+        ),
+        result(2, "+", Status::Uncaught, tap),
+        result(3, "x", Status::Skipped, "Uncompilable: type mismatch"),
+        result(
+            4,
+            "recv_internal",
+            Status::Skipped,
+            "Equivalent: compiled code matches the original",
+        ),
+    ]))
+    .unwrap();
+
+    assert_eq!(document["results"][0]["function"], json!(null));
+    assert_eq!(document["results"][1]["function"], json!("recv_internal"));
+    assert_eq!(document["results"][0]["tests"], json!(null));
+    assert_eq!(
+        document["results"][1]["tests"],
+        json!([
+            { "name": "deploys", "passed": true, "message": null },
+            { "name": "charges fee", "passed": false, "message": "expected 2" }
+        ])
+    );
+    assert_eq!(document["results"][1]["mutant"]["id"], json!(2));
+    assert_eq!(document["results"][0]["not_covered"], json!(true));
+    assert_eq!(document["results"][1]["not_covered"], json!(false));
+    assert_eq!(document["results"][0]["uncompilable"], json!(false));
+    assert_eq!(document["results"][2]["uncompilable"], json!(true));
+    assert_eq!(document["results"][2]["not_covered"], json!(false));
+    assert_eq!(document["results"][2]["equivalent"], json!(false));
+    assert_eq!(document["results"][3]["equivalent"], json!(true));
+}
@@ -0,0 +1,271 @@
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use mewt::types::{Status, Target};
+use mewt::{LanguageEngine, SqlStore};
+use muton::config::CompilerConfig;
+use muton::languages::compile::{
+    CompileMarks, EQUIVALENT, EQUIVALENT_SKIP, UNCOMPILABLE, UNCOMPILABLE_SKIP, check_compiles,
+    code_cell, mark_compile_outcomes,
+};
+use muton::languages::scope::Selection;
+use muton::languages::tact::engine::TactLanguageEngine;
+
+use crate::tact::integration_tests::create_test_target;
+use crate::utils;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+const SOURCE: &str = r#"
+contract C {
+    owner: Address;
+
+    receive("withdraw") {
+        require(sender() == self.owner, "only owner");
+        self.owner = sender();
+    }
+}
+"#;
+
+/// Write a stub compiler that rejects any file without a `require` call
+fn stub_compiler(dir: &Path) -> PathBuf {
+    let path = dir.join("stub-tact");
+    fs::write(
+        &path,
+        "#!/bin/sh\ngrep -q 'require(' \"$1\" || { echo 'missing require' >&2; exit 1; }\n",
+    )
+    .unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+/// Store a target and its generated mutants in a fresh database, returning
+/// the store and the stored mutant ids with their slugs
+async fn stored_mutants(dir: &Path, target: &Target) -> (SqlStore, Vec<(i64, String)>) {
+    let store = utils::create_store(dir).await;
+    let mut target = target.clone();
+    target.id = store.add_target(target.clone()).await.unwrap();
+    let mut ids = Vec::new();
+    for mutant in TactLanguageEngine::new().mutate(&target) {
+        let slug = mutant.mutation_slug.clone();
+        if let Some(id) = store.add_mutant(mutant).await.unwrap() {
+            ids.push((id, slug));
+        }
+    }
+    (store, ids)
+}
+
+fn tact_compiler(command: &str) -> CompilerConfig {
+    CompilerConfig {
+        tact: Some(command.to_string()),
+        ..CompilerConfig::default()
+    }
+}
+
+/// Slugs and outputs of the mutants that got an outcome, sorted
+async fn marked(store: &SqlStore, mutants: &[(i64, String)]) -> Vec<(String, String)> {
+    let mut marked = Vec::new();
+    for (id, slug) in mutants {
+        if let Some(outcome) = store.get_outcome(*id).await.unwrap() {
+            assert_eq!(outcome.status, Status::Skipped);
+            marked.push((slug.clone(), outcome.output));
+        }
+    }
+    marked.sort();
+    marked
+}
+
+#[tokio::test]
+async fn mutants_rejected_by_the_compiler_are_marked_uncompilable() {
+    let (tmp, target) = create_test_target(SOURCE);
+    let command = format!("{} {{file}}", stub_compiler(tmp.path()).display());
+    let (store, mutants) = stored_mutants(tmp.path(), &target).await;
+    let commands = tact_compiler(&command);
+    let none = CompilerConfig::default();
+
+    let marks = mark_compile_outcomes(&store, &commands, &none, &Selection::default())
+        .await
+        .unwrap();
+    assert_eq!(marks.uncompilable, 2);
+    assert_eq!(marks.equivalent, 0);
+    let again = mark_compile_outcomes(&store, &commands, &none, &Selection::default())
+        .await
+        .unwrap();
+    assert_eq!(again, CompileMarks::default());
+
+    let output = format!("{UNCOMPILABLE}: missing require");
+    assert_eq!(
+        marked(&store, &mutants).await,
+        [
+            ("RD".to_string(), output.clone()),
+            ("RHE".to_string(), output)
+        ]
+    );
+    assert_eq!(UNCOMPILABLE_SKIP.report(&store, true).await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn nothing_is_marked_when_the_original_does_not_compile() {
+    let (tmp, target) = create_test_target(SOURCE);
+    let (store, mutants) = stored_mutants(tmp.path(), &target).await;
+    let none = CompilerConfig::default();
+
+    let failing = tact_compiler("false {file}");
+    let marks = mark_compile_outcomes(&store, &failing, &failing, &Selection::default())
+        .await
+        .unwrap();
+    assert_eq!(marks, CompileMarks::default());
+    let marks = mark_compile_outcomes(&store, &none, &none, &Selection::default())
+        .await
+        .unwrap();
+    assert_eq!(marks, CompileMarks::default());
+    assert!(marked(&store, &mutants).await.is_empty());
+}
+
+#[test]
+fn compile_check_reports_stderr_and_cleans_up() {
+    let (tmp, target) = create_test_target(SOURCE);
+    let command = format!("{} {{file}}", stub_compiler(tmp.path()).display());
+
+    assert!(check_compiles(&target, &command, SOURCE, TIMEOUT).is_ok());
+    assert_eq!(
+        check_compiles(&target, &command, "contract C {}", TIMEOUT),
+        Err("missing require".to_string())
+    );
+
+    let mut entries: Vec<_> = fs::read_dir(tmp.path())
+        .unwrap()
+        .map(|e| e.unwrap().file_name())
+        .collect();
+    entries.sort();
+    assert_eq!(entries.len(), 2, "scratch files left behind: {entries:?}");
+}
+
+#[test]
+fn compiler_runs_are_killed_after_the_timeout() {
+    let (_tmp, target) = create_test_target(SOURCE);
+    let start = Instant::now();
+
+    let error = check_compiles(&target, "sleep 30", SOURCE, Duration::from_millis(200))
+        .expect_err("a compiler that outlives the timeout should fail");
+
+    assert!(error.contains("timed out"), "{error}");
+    assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+const GUARDED: &str = r#"
+contract C {
+    count: Int;
+
+    receive("dec") {
+        require(self.count > 0, "empty");
+        self.count -= 1;
+    }
+}
+"#;
+
+/// A stub compiler that treats `>=` and `>` alike, standing in for a real
+/// compiler folding two sources into the same code. It prints the code cell
+/// as hex after a line that differs on every run, like a build timestamp.
+const FOLDING_COMPILER: &str =
+    "date +%N; printf 'b5ee9c72%s\\n' \"$(sed 's/>=/>/g' {file} | cksum | tr -d ' ')\"";
+
+fn equivalence(command: &str) -> (CompilerConfig, CompilerConfig) {
+    (CompilerConfig::default(), tact_compiler(command))
+}
+
+#[tokio::test]
+async fn mutants_with_identical_code_cells_are_marked_equivalent() {
+    let (tmp, target) = create_test_target(GUARDED);
+    let (store, mutants) = stored_mutants(tmp.path(), &target).await;
+    let (compile, equivalence) = equivalence(FOLDING_COMPILER);
+
+    let marks = mark_compile_outcomes(&store, &compile, &equivalence, &Selection::default())
+        .await
+        .unwrap();
+
+    assert_eq!(marks.equivalent, 1);
+    assert_eq!(
+        marked(&store, &mutants).await,
+        [(
+            "COS".to_string(),
+            format!("{EQUIVALENT}: compiled code matches the original")
+        )]
+    );
+    assert_eq!(EQUIVALENT_SKIP.report(&store, true).await.unwrap(), 1);
+    assert_eq!(UNCOMPILABLE_SKIP.report(&store, true).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn equivalence_reads_a_boc_from_the_output_file() {
+    let (tmp, target) = create_test_target(GUARDED);
+    let (store, _) = stored_mutants(tmp.path(), &target).await;
+    let command =
+        "{ printf '\\265\\356\\234\\162'; sed 's/>=/>/g' {file}; } > {output} && echo {file}";
+    let (compile, equivalence) = equivalence(command);
+
+    let marks = mark_compile_outcomes(&store, &compile, &equivalence, &Selection::default())
+        .await
+        .unwrap();
+    assert_eq!(marks.equivalent, 1);
+}
+
+#[tokio::test]
+async fn each_mutant_is_compiled_once_for_both_checks() {
+    let (tmp, target) = create_test_target(GUARDED);
+    let (store, mutants) = stored_mutants(tmp.path(), &target).await;
+    let log = tmp.path().join("compiler.log");
+    // Every source compiles to the same code, except those using `<`
+    let command = format!(
+        "echo equivalence >> '{}' && ! grep -q ' < ' {{file}} && echo b5ee9c72aa",
+        log.display()
+    );
+    let compile = tact_compiler(&format!("echo compile >> '{}'", log.display()));
+
+    let marks = mark_compile_outcomes(
+        &store,
+        &compile,
+        &tact_compiler(&command),
+        &Selection::default(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(marks.uncompilable, 1);
+    assert_eq!(marks.equivalent, mutants.len() - 1);
+    let runs = fs::read_to_string(&log).unwrap();
+    assert_eq!(runs.lines().count(), mutants.len() + 1);
+    assert!(runs.lines().all(|run| run == "equivalence"), "{runs}");
+}
+
+#[tokio::test]
+async fn output_without_a_code_cell_marks_nothing_equivalent() {
+    let (tmp, target) = create_test_target(GUARDED);
+    let (store, mutants) = stored_mutants(tmp.path(), &target).await;
+    let (compile, equivalence) = equivalence("echo compiled");
+
+    let marks = mark_compile_outcomes(&store, &compile, &equivalence, &Selection::default())
+        .await
+        .unwrap();
+    assert_eq!(marks, CompileMarks::default());
+    assert!(marked(&store, &mutants).await.is_empty());
+}
+
+#[test]
+fn code_cells_are_found_in_hex_base64_and_binary_output() {
+    assert_eq!(
+        code_cell(b"Compiled at 12:00\ncode: B5EE9C72ABCD\ndone\n").as_deref(),
+        Some("b5ee9c72abcd")
+    );
+    assert_eq!(
+        code_cell(br#"{"path":"/tmp/x","codeBoc64":"te6ccgEBAQ+/="}"#).as_deref(),
+        Some("te6ccgEBAQ+/=")
+    );
+    assert_eq!(
+        code_cell(&[0xb5, 0xee, 0x9c, 0x72, 0x01]).as_deref(),
+        Some("b5ee9c7201")
+    );
+    assert_eq!(code_cell(b"PROGRAM{ DECLPROC main }END>c"), None);
+}
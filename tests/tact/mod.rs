@@ -1,3 +1,6 @@
+mod compile_tests;
 mod integration_tests;
 mod mutations;
 mod parser_tests;
+mod rules_tests;
+mod suppression_tests;
@@ -0,0 +1,59 @@
+use crate::tact::integration_tests::mutants_for_slug;
+
+#[test]
+fn afr_replaces_compared_address_fields() {
+    let source = r#"
+    contract C {
+        owner: Address;
+        treasury: Address = address("EQD__________________________________________0vo");
+        counter: Int = 0;
+
+        receive("withdraw") {
+            require(sender() == self.owner, "only owner");
+            require(self.counter != 0, "empty");
+            send(SendParameters{ to: self.treasury, value: 0 });
+        }
+    }
+    "#;
+
+    let mutants = mutants_for_slug(source, "AFR");
+    let replacements: Vec<(&str, &str)> = mutants
+        .iter()
+        .map(|m| (m.old_text.as_str(), m.new_text.as_str()))
+        .collect();
+    assert_eq!(
+        replacements,
+        [
+            ("self.owner", "myAddress()"),
+            ("self.owner", "self.treasury"),
+        ]
+    );
+}
+
+#[test]
+fn afr_skips_the_field_on_the_other_side() {
+    let source = r#"
+    contract C {
+        owner: Address;
+        admin: Address;
+        treasury: Address;
+
+        receive("handover") {
+            require(self.admin == self.owner, "not handed over");
+        }
+    }
+    "#;
+
+    let replacements: Vec<(String, String)> = mutants_for_slug(source, "AFR")
+        .into_iter()
+        .map(|m| (m.old_text, m.new_text))
+        .collect();
+    let expected = [
+        ("self.admin", "myAddress()"),
+        ("self.admin", "self.treasury"),
+        ("self.owner", "myAddress()"),
+        ("self.owner", "self.treasury"),
+    ]
+    .map(|(old, new)| (old.to_string(), new.to_string()));
+    assert_eq!(replacements, expected);
+}
@@ -0,0 +1,28 @@
+use crate::tact::integration_tests::{first_mutated_source, mutants_for_slug};
+
+#[test]
+fn bhe_empties_bounced_handlers() {
+    let source = r#"
+    message Transfer { amount: Int }
+
+    contract C {
+        balance: Int = 0;
+
+        receive(msg: Transfer) {
+            self.balance -= msg.amount;
+        }
+
+        bounced(msg: bounced<Transfer>) {
+            self.balance += msg.amount;
+        }
+    }
+    "#;
+
+    assert_eq!(mutants_for_slug(source, "BHE").len(), 1);
+    let mutated = first_mutated_source(source, "BHE").expect("BHE mutant");
+    assert!(
+        mutated.contains("bounced(msg: bounced<Transfer>) {}")
+            && mutated.contains("self.balance -= msg.amount;"),
+        "expected BHE to empty only the bounced body; mutated source: {mutated}"
+    );
+}
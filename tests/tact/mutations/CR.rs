@@ -12,3 +12,16 @@ fn cr_wraps_statement_in_block_comment() {
         "expected CR mutant to wrap the statement in a block comment; mutated: {mutated}"
     );
 }
+
+#[test]
+fn cr_comments_out_the_statement_terminator() {
+    let source = r#"
+    contract C { fun f() { let x: Int = 1; } }
+    "#;
+
+    let mutated = first_mutated_source(source, "CR").expect("CR mutant");
+    assert!(
+        mutated.contains("{ /* let x: Int = 1 */ }"),
+        "expected CR mutant not to leave a stray `;`; mutated: {mutated}"
+    );
+}
@@ -0,0 +1,26 @@
+use crate::tact::integration_tests::{first_mutated_source, mutants_for_slug};
+
+#[test]
+fn ehe_empties_external_handlers() {
+    let source = r#"
+    contract C {
+        seqno: Int = 0;
+
+        external("tick") {
+            acceptMessage();
+            self.seqno += 1;
+        }
+
+        receive("tock") {
+            self.seqno += 1;
+        }
+    }
+    "#;
+
+    assert_eq!(mutants_for_slug(source, "EHE").len(), 1);
+    let mutated = first_mutated_source(source, "EHE").expect("EHE mutant");
+    assert!(
+        mutated.contains(r#"external("tick") {}"#),
+        "expected EHE to empty the external body; mutated source: {mutated}"
+    );
+}
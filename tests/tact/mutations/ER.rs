@@ -12,3 +12,16 @@ fn er_replaces_statements_with_require_false() {
         "expected ER mutant to insert `require(false);`; mutated: {mutated}"
     );
 }
+
+#[test]
+fn er_replaces_the_statement_terminator() {
+    let source = r#"
+    contract C { fun f() { let x: Int = 1; } }
+    "#;
+
+    let mutated = first_mutated_source(source, "ER").expect("ER mutant");
+    assert!(
+        mutated.contains("{ require(false); }"),
+        "expected ER mutant not to leave a stray `;`; mutated: {mutated}"
+    );
+}
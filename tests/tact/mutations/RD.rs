@@ -0,0 +1,48 @@
+use crate::tact::integration_tests::{first_mutated_source, mutants_for_slug};
+
+#[test]
+fn rd_removes_guard_statements() {
+    let source = r#"
+    contract C {
+        owner: Address;
+        stopped: Bool = false;
+
+        receive("withdraw") {
+            require(sender() == self.owner, "only owner");
+            throwUnless(132, sender() == self.owner);
+            throwIf(133, self.stopped);
+            nativeThrowUnless(134, self.owner != myAddress());
+            // require(false, "commented out");
+        }
+    }
+    "#;
+
+    let mutants = mutants_for_slug(source, "RD");
+    let removed: Vec<&str> = mutants.iter().map(|m| m.old_text.as_str()).collect();
+    assert_eq!(
+        removed,
+        [
+            r#"require(sender() == self.owner, "only owner");"#,
+            "throwUnless(132, sender() == self.owner);",
+            "throwIf(133, self.stopped);",
+            "nativeThrowUnless(134, self.owner != myAddress());",
+        ]
+    );
+    assert!(mutants.iter().all(|m| m.new_text.is_empty()));
+}
+
+#[test]
+fn rd_keeps_guards_nested_in_expressions() {
+    let source = r#"
+    contract C {
+        receive("check") {
+            let ok: Bool = self.check(require(true, "never"));
+        }
+
+        fun check(x: Bool): Bool { return x; }
+    }
+    "#;
+
+    assert!(mutants_for_slug(source, "RD").is_empty());
+    assert!(first_mutated_source(source, "RD").is_none());
+}
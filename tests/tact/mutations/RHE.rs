@@ -0,0 +1,27 @@
+use crate::tact::integration_tests::{first_mutated_source, mutants_for_slug};
+
+#[test]
+fn rhe_empties_receive_handlers() {
+    let source = r#"
+    contract C {
+        counter: Int = 0;
+
+        receive("increment") {
+            self.counter += 1;
+        }
+
+        receive() {}
+    }
+    "#;
+
+    assert_eq!(
+        mutants_for_slug(source, "RHE").len(),
+        1,
+        "expected RHE to skip handlers that are already empty"
+    );
+    let mutated = first_mutated_source(source, "RHE").expect("RHE mutant");
+    assert!(
+        mutated.contains(r#"receive("increment") {}"#),
+        "expected RHE to empty the receive body; mutated source: {mutated}"
+    );
+}
@@ -0,0 +1,33 @@
+use crate::tact::integration_tests::mutants_for_slug;
+
+#[test]
+fn rn_negates_guard_conditions() {
+    let source = r#"
+    contract C {
+        owner: Address;
+        stopped: Bool = false;
+
+        receive("withdraw") {
+            require(sender() == self.owner, "only owner");
+            throwUnless(132, sender() == self.owner);
+            throwIf(133, self.stopped);
+            nativeThrowUnless(134, true);
+        }
+    }
+    "#;
+
+    let mutants = mutants_for_slug(source, "RN");
+    let replacements: Vec<(&str, &str)> = mutants
+        .iter()
+        .map(|m| (m.old_text.as_str(), m.new_text.as_str()))
+        .collect();
+    assert_eq!(
+        replacements,
+        [
+            ("sender() == self.owner", "!(sender() == self.owner)"),
+            ("sender() == self.owner", "!(sender() == self.owner)"),
+            ("self.stopped", "!(self.stopped)"),
+            ("true", "false"),
+        ]
+    );
+}
@@ -0,0 +1,31 @@
+use crate::tact::integration_tests::{first_mutated_source, mutants_for_slug};
+
+#[test]
+fn rt_makes_guards_always_pass() {
+    let source = r#"
+    contract C {
+        owner: Address;
+        stopped: Bool = false;
+
+        receive("withdraw") {
+            require(sender() == self.owner, "only owner");
+            throwUnless(132, sender() == self.owner);
+            throwIf(133, self.stopped);
+            nativeThrowUnless(134, true);
+        }
+    }
+    "#;
+
+    let mutants = mutants_for_slug(source, "RT");
+    let new_texts: Vec<&str> = mutants.iter().map(|m| m.new_text.as_str()).collect();
+    assert_eq!(
+        new_texts,
+        ["true", "true", "false"],
+        "expected RT to skip guards that already pass"
+    );
+    let mutated = first_mutated_source(source, "RT").expect("RT mutant");
+    assert!(
+        mutated.contains(r#"require(true, "only owner");"#),
+        "expected RT to keep the require message; mutated source: {mutated}"
+    );
+}
@@ -0,0 +1,34 @@
+use crate::tact::integration_tests::{first_mutated_source, mutants_for_slug};
+
+#[test]
+fn sbf_flips_send_parameters_bounce() {
+    let source = r#"
+    contract C {
+        receive("refund") {
+            send(SendParameters{ to: sender(), value: 0, bounce: true });
+        }
+    }
+    "#;
+
+    let mutated = first_mutated_source(source, "SBF").expect("SBF mutant");
+    assert!(
+        mutated.contains("bounce: false"),
+        "expected SBF to flip bounce to false; mutated source: {mutated}"
+    );
+}
+
+#[test]
+fn sbf_flips_forward_bounce_argument() {
+    let source = r#"
+    contract C {
+        receive("ping") {
+            self.forward(sender(), null, false, null);
+        }
+    }
+    "#;
+
+    let mutants = mutants_for_slug(source, "SBF");
+    assert_eq!(mutants.len(), 1, "expected one SBF mutant");
+    assert_eq!(mutants[0].old_text, "false");
+    assert_eq!(mutants[0].new_text, "true");
+}
@@ -0,0 +1,35 @@
+use crate::tact::integration_tests::{first_mutated_source, mutants_for_slug};
+
+#[test]
+fn sbr_drops_send_parameters_body() {
+    let source = r#"
+    contract C {
+        receive("refund") {
+            send(SendParameters{ to: sender(), body: "Refund".asComment(), value: 0 });
+        }
+    }
+    "#;
+
+    let mutated = first_mutated_source(source, "SBR").expect("SBR mutant");
+    assert!(
+        mutated.contains("SendParameters{ to: sender(), value: 0 }"),
+        "expected SBR to drop the body field; mutated source: {mutated}"
+    );
+}
+
+#[test]
+fn sbr_nulls_reply_body() {
+    let source = r#"
+    contract C {
+        receive("ping") {
+            self.reply("pong".asComment());
+            self.notify(null);
+        }
+    }
+    "#;
+
+    let mutants = mutants_for_slug(source, "SBR");
+    assert_eq!(mutants.len(), 1, "expected one SBR mutant");
+    assert_eq!(mutants[0].old_text, "\"pong\".asComment()");
+    assert_eq!(mutants[0].new_text, "null");
+}
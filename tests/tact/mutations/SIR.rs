@@ -0,0 +1,96 @@
+use crate::tact::integration_tests::{first_mutated_source, mutants_for_slug};
+
+#[test]
+fn sir_replaces_sender_with_other_addresses() {
+    let source = r#"
+    contract C(admin: Address) {
+        owner: Address;
+        backup: Address?;
+        counter: Int = 0;
+
+        receive("withdraw") {
+            require(sender() == self.owner, "only owner");
+            require(context().sender != self.admin, "not admin");
+            // sender() in a comment is left alone
+        }
+    }
+    "#;
+
+    let mutants = mutants_for_slug(source, "SIR");
+    let replacements: Vec<(&str, &str)> = mutants
+        .iter()
+        .map(|m| (m.old_text.as_str(), m.new_text.as_str()))
+        .collect();
+    assert_eq!(
+        replacements,
+        [
+            ("sender()", "myAddress()"),
+            ("sender()", "self.admin"),
+            ("context().sender", "myAddress()"),
+            ("context().sender", "self.owner"),
+        ]
+    );
+    let mutated = first_mutated_source(source, "SIR").expect("SIR mutant");
+    assert!(
+        mutated.contains("require(myAddress() == self.owner"),
+        "expected SIR to replace sender(); mutated source: {mutated}"
+    );
+}
+
+#[test]
+fn sir_replaces_sender_with_every_address_field_outside_comparisons() {
+    let source = r#"
+    contract C {
+        owner: Address;
+        admin: Address;
+
+        receive("forward") {
+            send(SendParameters { to: sender(), value: 0 });
+        }
+    }
+    "#;
+
+    let replacements: Vec<String> = mutants_for_slug(source, "SIR")
+        .into_iter()
+        .map(|m| m.new_text)
+        .collect();
+    assert_eq!(replacements, ["myAddress()", "self.owner", "self.admin"]);
+}
+
+#[test]
+fn sir_replaces_sender_outside_contracts_with_my_address() {
+    let source = r#"
+    fun caller(): Address {
+        return sender();
+    }
+    "#;
+
+    let replacements: Vec<(String, String)> = mutants_for_slug(source, "SIR")
+        .into_iter()
+        .map(|m| (m.old_text, m.new_text))
+        .collect();
+    assert_eq!(
+        replacements,
+        [("sender()".to_string(), "myAddress()".to_string())]
+    );
+}
+
+#[test]
+fn sir_skips_the_field_sender_is_assigned_to() {
+    let source = r#"
+    contract C {
+        owner: Address;
+        admin: Address;
+
+        receive("claim") {
+            self.owner = sender();
+        }
+    }
+    "#;
+
+    let replacements: Vec<String> = mutants_for_slug(source, "SIR")
+        .into_iter()
+        .map(|m| m.new_text)
+        .collect();
+    assert_eq!(replacements, ["myAddress()", "self.admin"]);
+}
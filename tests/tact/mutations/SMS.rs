@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+
+use crate::tact::integration_tests::mutants_for_slug;
+
+#[test]
+fn sms_replaces_send_mode_constants() {
+    let source = r#"
+    contract C {
+        receive("refund") {
+            send(SendParameters{ to: sender(), value: 0, mode: SendRemainingValue + SendIgnoreErrors });
+        }
+    }
+    "#;
+
+    let mutants = mutants_for_slug(source, "SMS");
+    let rewrites: HashSet<_> = mutants
+        .iter()
+        .map(|m| format!("{} -> {}", m.old_text, m.new_text))
+        .collect();
+    for expected in [
+        "SendRemainingValue -> SendRemainingBalance",
+        "SendIgnoreErrors -> SendPayGasSeparately",
+    ] {
+        assert!(
+            rewrites.contains(expected),
+            "missing SMS rewrite `{expected}`; rewrites: {rewrites:?}"
+        );
+    }
+    assert_eq!(
+        rewrites.len(),
+        2,
+        "SMS should not introduce a mode already present: {rewrites:?}"
+    );
+}
+
+#[test]
+fn sms_never_combines_two_base_modes() {
+    let source = r#"
+    contract C {
+        receive("drain") {
+            send(SendParameters{ to: sender(), value: 0, mode: SendRemainingBalance });
+            send(SendParameters{ to: sender(), value: 0, mode: SendPayGasSeparately });
+        }
+    }
+    "#;
+
+    let rewrites: Vec<_> = mutants_for_slug(source, "SMS")
+        .iter()
+        .map(|m| format!("{} -> {}", m.old_text, m.new_text))
+        .collect();
+    assert_eq!(
+        rewrites,
+        [
+            "SendRemainingBalance -> SendRemainingValue",
+            "SendPayGasSeparately -> SendIgnoreErrors",
+        ]
+    );
+}
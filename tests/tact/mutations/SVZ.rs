@@ -0,0 +1,35 @@
+use crate::tact::integration_tests::mutants_for_slug;
+
+#[test]
+fn svz_zeroes_send_parameters_value() {
+    let source = r#"
+    contract C {
+        receive("refund") {
+            send(SendParameters{ to: sender(), value: ton("0.1"), bounce: false });
+        }
+    }
+    "#;
+
+    let mutants = mutants_for_slug(source, "SVZ");
+    assert_eq!(mutants.len(), 1, "expected one SVZ mutant");
+    assert_eq!(mutants[0].old_text, "ton(\"0.1\")");
+    assert_eq!(mutants[0].new_text, "0");
+}
+
+#[test]
+fn svz_ignores_values_outside_send_parameters() {
+    let source = r#"
+    contract C {
+        receive("store") {
+            let p: Point = Point{ x: 1, value: 2 };
+            message(MessageParameters{ to: sender(), value: 0 });
+        }
+    }
+    "#;
+
+    let mutants = mutants_for_slug(source, "SVZ");
+    assert!(
+        mutants.is_empty(),
+        "expected no SVZ mutants for non-send structs or zero values"
+    );
+}
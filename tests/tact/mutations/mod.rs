@@ -2,12 +2,16 @@
 
 #[path = "AAOS.rs"]
 mod aaos;
+#[path = "AFR.rs"]
+mod afr;
 #[path = "AOS.rs"]
 mod aos;
 #[path = "AS.rs"]
 mod r#as;
 #[path = "BAOS.rs"]
 mod baos;
+#[path = "BHE.rs"]
+mod bhe;
 #[path = "BL.rs"]
 mod bl;
 #[path = "BOS.rs"]
@@ -16,6 +20,8 @@ mod bos;
 mod cos;
 #[path = "CR.rs"]
 mod cr;
+#[path = "EHE.rs"]
+mod ehe;
 #[path = "ER.rs"]
 mod er;
 #[path = "IF.rs"]
@@ -28,12 +34,30 @@ mod lc;
 mod los;
 #[path = "NR.rs"]
 mod nr;
+#[path = "RD.rs"]
+mod rd;
+#[path = "RHE.rs"]
+mod rhe;
+#[path = "RN.rs"]
+mod rn;
+#[path = "RT.rs"]
+mod rt;
 #[path = "RZ.rs"]
 mod rz;
 #[path = "SAOS.rs"]
 mod saos;
+#[path = "SBF.rs"]
+mod sbf;
+#[path = "SBR.rs"]
+mod sbr;
+#[path = "SIR.rs"]
+mod sir;
+#[path = "SMS.rs"]
+mod sms;
 #[path = "SOS.rs"]
 mod sos;
+#[path = "SVZ.rs"]
+mod svz;
 #[path = "TF.rs"]
 mod tf;
 #[path = "TT.rs"]
@@ -1,7 +1,14 @@
 use std::collections::HashSet;
 use std::sync::OnceLock;
 
+use mewt::LanguageEngine;
 use mewt::utils::parse_source;
+use muton::languages::diagnostics::{ParseError, parse_errors};
+use muton::languages::scope::{enclosing_function, filter_to_functions};
+use muton::languages::tact::engine::TactLanguageEngine;
+use muton::languages::tact::patterns::function_spans;
+
+use crate::tact::integration_tests::create_test_target;
 use tree_sitter::Language as TsLanguage;
 
 static TACT_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
@@ -127,3 +134,80 @@ fn finds_comment_nodes() {
     }
     assert!(saw_comment, "expected to find comment node");
 }
+
+#[test]
+fn reports_syntax_errors_with_snippet() {
+    let source = "contract C {\n    fun f() {\n        let x: Int = 1 +* 2;\n    }\n}\n";
+    let tree = parse_tact(source);
+    assert_eq!(
+        parse_errors(tree.root_node(), source),
+        [ParseError {
+            line: 3,
+            column: 24,
+            message: "syntax error near `+`".to_string(),
+        }]
+    );
+}
+
+const HANDLERS: &str = r#"
+contract Vault {
+    balance: Int = 0;
+
+    init() { self.balance = 1; }
+
+    receive("withdraw") {
+        self.balance = self.balance - 1;
+    }
+
+    receive(msg: Deposit) {
+        self.balance = self.balance + msg.amount;
+    }
+
+    bounced(msg: bounced<Deposit>) {
+        self.balance = self.balance - msg.amount;
+    }
+
+    get fun balance(): Int {
+        return self.balance + 0;
+    }
+}
+"#;
+
+#[test]
+fn function_spans_name_handlers_by_parameter() {
+    let tree = parse_tact(HANDLERS);
+    let names: Vec<_> = function_spans(tree.root_node(), HANDLERS)
+        .into_iter()
+        .map(|f| f.name)
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "init()",
+            r#"receive("withdraw")"#,
+            "receive(msg: Deposit)",
+            "bounced(msg: bounced<Deposit>)",
+            "balance",
+        ]
+    );
+}
+
+#[test]
+fn mutants_can_be_restricted_to_one_receiver() {
+    let (_tmp, target) = create_test_target(HANDLERS);
+    let tree = parse_tact(HANDLERS);
+    let functions = function_spans(tree.root_node(), HANDLERS);
+    let mutants = TactLanguageEngine::new().mutate(&target);
+
+    let selectors = [r#"receive( "withdraw" )"#.to_string()];
+    let kept = filter_to_functions(&target, &functions, mutants.clone(), &selectors);
+    assert!(!kept.is_empty() && kept.len() < mutants.len());
+    assert!(kept.iter().all(|m| {
+        enclosing_function(&functions, m.byte_offset as usize)
+            .is_some_and(|f| f.name == r#"receive("withdraw")"#)
+    }));
+
+    // A bare handler keyword selects every handler of that kind
+    let receivers = filter_to_functions(&target, &functions, mutants, &["receive".to_string()]);
+    assert!(receivers.len() > kept.len());
+}
@@ -0,0 +1,126 @@
+use mewt::LanguageEngine;
+use mewt::types::MutationSeverity;
+use muton::config::RuleConfig;
+use muton::languages::tact::engine::TactLanguageEngine;
+
+use crate::tact::integration_tests::create_test_target;
+
+fn rule(language: &str, slug: &str, query: &str, replacement: &str) -> RuleConfig {
+    RuleConfig {
+        language: language.to_string(),
+        slug: slug.to_string(),
+        description: format!("{slug}: user-defined rule"),
+        severity: "high".to_string(),
+        query: query.to_string(),
+        capture: None,
+        replacement: replacement.to_string(),
+    }
+}
+
+const SOURCE: &str = r#"
+contract C {
+    owner: Address;
+
+    receive("withdraw") {
+        require(sender() == self.owner, "only owner");
+        // sender() in a comment is left alone
+    }
+}
+"#;
+
+#[test]
+fn query_rule_registers_and_mutates() {
+    let engine = TactLanguageEngine::with_rules(&[rule(
+        "Tact",
+        "SMA",
+        r#"((static_call_expression name: (identifier) @fn) @target (#eq? @fn "sender"))"#,
+        "myAddress()",
+    )]);
+
+    let mutation = engine
+        .get_mutations()
+        .iter()
+        .find(|m| m.slug == "SMA")
+        .expect("rule should be registered alongside built-in mutations");
+    assert_eq!(mutation.severity, MutationSeverity::High);
+    assert!(engine.get_mutations().iter().any(|m| m.slug == "ER"));
+
+    let (_tmp, target) = create_test_target(SOURCE);
+    let mutants: Vec<_> = engine
+        .mutate(&target)
+        .into_iter()
+        .filter(|m| m.mutation_slug == "SMA")
+        .collect();
+    assert_eq!(mutants.len(), 1);
+    assert_eq!(mutants[0].old_text, "sender()");
+    assert_eq!(mutants[0].new_text, "myAddress()");
+}
+
+#[test]
+fn replacement_template_expands_captures() {
+    let engine = TactLanguageEngine::with_rules(&[RuleConfig {
+        capture: Some("cond".to_string()),
+        ..rule(
+            "Tact",
+            "RQN",
+            r#"(static_call_expression name: (identifier) @fn arguments: (argument_list (argument value: (_) @cond)) (#eq? @fn "require"))"#,
+            "!({cond})",
+        )
+    }]);
+
+    let (_tmp, target) = create_test_target(SOURCE);
+    let new_texts: Vec<_> = engine
+        .mutate(&target)
+        .into_iter()
+        .filter(|m| m.mutation_slug == "RQN")
+        .map(|m| m.new_text)
+        .collect();
+    assert_eq!(
+        new_texts,
+        ["!(sender() == self.owner)", r#"!("only owner")"#]
+    );
+}
+
+#[test]
+fn captured_text_is_not_expanded_again() {
+    let engine = TactLanguageEngine::with_rules(&[RuleConfig {
+        capture: Some("call".to_string()),
+        ..rule(
+            "Tact",
+            "RQI",
+            r#"((static_call_expression name: (identifier) @fn arguments: (argument_list (argument value: (_) @cond) (argument value: (string) @msg))) @call (#eq? @fn "require"))"#,
+            "{fn}(!({cond}), {msg})",
+        )
+    }]);
+
+    let source = r#"
+contract C {
+    tag: String;
+
+    receive("check") {
+        require(self.tag == "{msg}", "bad tag");
+    }
+}
+"#;
+    let (_tmp, target) = create_test_target(source);
+    let new_texts: Vec<_> = engine
+        .mutate(&target)
+        .into_iter()
+        .filter(|m| m.mutation_slug == "RQI")
+        .map(|m| m.new_text)
+        .collect();
+    assert_eq!(new_texts, [r#"require(!(self.tag == "{msg}"), "bad tag")"#]);
+}
+
+#[test]
+fn invalid_and_foreign_rules_are_skipped() {
+    let engine = TactLanguageEngine::with_rules(&[
+        rule("Tact", "BAD", "(not_a_node) @target", "x"),
+        rule("Tact", "NOCAP", "(identifier) @name", "x"),
+        rule("Tact", "ER", "(identifier) @target", "x"),
+        rule("FunC", "FUNC", "(identifier) @target", "x"),
+    ]);
+
+    let builtin = TactLanguageEngine::new().get_mutations().len();
+    assert_eq!(engine.get_mutations().len(), builtin);
+}
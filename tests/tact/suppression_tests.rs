@@ -0,0 +1,125 @@
+use crate::tact::integration_tests::mutants_for_slug;
+
+#[test]
+fn ignore_next_line_suppresses_the_following_line() {
+    let source = r#"
+contract C {
+    fun f(x: Int) {
+        // muton-ignore-next-line
+        require(x > 0 && x < 10, "range");
+        let y: Int = x > 1 && x < 5 ? 1 : 0;
+    }
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "LOS");
+    assert!(!mutants.is_empty());
+    assert!(
+        mutants.iter().all(|m| m.line_offset == 5),
+        "expected only the unsuppressed line to be mutated"
+    );
+}
+
+#[test]
+fn slug_list_on_its_own_line_applies_to_the_next_line() {
+    let source = r#"
+contract C {
+    fun f(x: Int): Int {
+        // muton-ignore: AOS
+        return x + 1;
+    }
+}
+"#;
+
+    assert!(mutants_for_slug(source, "AOS").is_empty());
+    assert!(!mutants_for_slug(source, "ER").is_empty());
+}
+
+#[test]
+fn ignore_block_covers_a_whole_receiver() {
+    let source = r#"
+contract C {
+    x: Int = 0;
+
+    /* muton-ignore-start */
+    receive("a") {
+        self.x = self.x + 1;
+    }
+    /* muton-ignore-end */
+
+    receive("b") {
+        self.x = self.x + 2;
+    }
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "RHE");
+    assert_eq!(mutants.len(), 1);
+    assert_eq!(mutants[0].line_offset, 10);
+}
+
+#[test]
+fn ignored_line_suppresses_mutants_spanning_it() {
+    let source = r#"
+contract C {
+    x: Int = 0;
+
+    receive("a") {
+        // muton-ignore: RHE
+        self.x = self.x + 1;
+    }
+
+    receive("b") {
+        self.x = self.x + 2;
+    }
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "RHE");
+    assert_eq!(mutants.len(), 1);
+    assert_eq!(mutants[0].line_offset, 9);
+}
+
+#[test]
+fn ignore_function_covers_the_next_receiver() {
+    let source = r#"
+contract C {
+    x: Int = 0;
+
+    // muton-ignore-function: AOS
+    receive("a") {
+        self.x = self.x + 1;
+    }
+
+    receive("b") {
+        self.x = self.x + 2;
+    }
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "AOS");
+    assert!(!mutants.is_empty());
+    assert!(mutants.iter().all(|m| m.line_offset == 10));
+    assert_eq!(mutants_for_slug(source, "RHE").len(), 2);
+}
+
+#[test]
+fn directives_must_open_the_comment() {
+    let source = r#"
+contract C {
+    fun f(x: Int): Int {
+        // TODO: drop the muton-ignore below once covered
+        return x + 1;
+        /// muton-ignore-next-line
+        return x + 2;
+    }
+}
+"#;
+
+    let lines: Vec<u32> = mutants_for_slug(source, "AOS")
+        .iter()
+        .map(|m| m.line_offset)
+        .collect();
+    assert!(lines.contains(&4), "{lines:?}");
+    assert!(!lines.contains(&6), "{lines:?}");
+}
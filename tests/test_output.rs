@@ -0,0 +1,168 @@
+use muton::effectiveness::{EffectivenessReport, MutantTests, TestKills};
+use std::process::Command;
+
+use muton::test_output::{
+    TestCase, output_file, parse_jest_json, parse_tap, parse_test_output, print_output_file,
+};
+
+const JEST_JSON: &str = r#"{"numFailedTests":1,"testResults":[{"name":"/p/tests/Counter.spec.ts","assertionResults":[{"fullName":"Counter should deploy","status":"passed","title":"should deploy","failureMessages":[]},{"fullName":"Counter should increase","status":"failed","title":"should increase","failureMessages":["\u001b[2mexpect(\u001b[22mreceived\u001b[2m).toEqual(\u001b[22mexpected\u001b[2m)\u001b[22m\n\nExpected: 2n\nReceived: 1n"]},{"fullName":"Counter is todo","status":"todo","title":"is todo","failureMessages":[]}]}]}"#;
+
+const TAP: &str = "TAP version 13
+# Subtest: wallet
+ok 1 - accepts owner
+not ok 2 - rejects stranger
+  ---
+  message: 'Expected transaction to fail'
+  stack: |-
+    at tests/Wallet.spec.ts:10:5
+  ...
+ok 3 - skipped check # SKIP not ready
+not ok 4 - planned check # TODO write the contract side
+  ---
+  message: 'Not implemented'
+  ...
+ok 5 - todo that passes # todo
+1..5
+";
+
+fn outcome_output(stdout: &str) -> String {
+    format!("STDOUT:\n{stdout}\n\n\nSTDERR:\nPASS tests/Other.spec.ts\n")
+}
+
+#[test]
+fn jest_json_yields_cases_and_first_assertion_message() {
+    let cases = parse_jest_json(&format!("some log line\n{JEST_JSON}\n")).unwrap();
+    assert_eq!(
+        cases,
+        [
+            TestCase {
+                name: "Counter should deploy".to_string(),
+                passed: true,
+                message: None,
+            },
+            TestCase {
+                name: "Counter should increase".to_string(),
+                passed: false,
+                message: Some("expect(received).toEqual(expected)".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn tap_yields_cases_with_diagnostic_messages() {
+    let cases = parse_tap(TAP).unwrap();
+    let summary: Vec<_> = cases
+        .iter()
+        .map(|c| (c.name.as_str(), c.passed, c.message.as_deref()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("accepts owner", true, None),
+            (
+                "rejects stranger",
+                false,
+                Some("Expected transaction to fail")
+            ),
+        ]
+    );
+    assert_eq!(parse_tap("okay then\n1..0\n"), None);
+}
+
+#[test]
+fn tap_directives_need_the_separating_hash() {
+    let cases = parse_tap("ok 1 - pays fee #2\nok 2 - skips # Skip no funds\n").unwrap();
+    assert_eq!(cases.len(), 1);
+    assert_eq!(cases[0].name, "pays fee #2");
+}
+
+#[test]
+fn output_file_is_found_in_either_flag_form() {
+    assert_eq!(
+        output_file("npx jest --json --outputFile=out/report.json"),
+        Some("out/report.json")
+    );
+    assert_eq!(
+        output_file("npx jest --json --outputFile 'report.json' --silent"),
+        Some("report.json")
+    );
+    assert_eq!(output_file("npx jest --json"), None);
+    assert_eq!(print_output_file("npx jest --json"), "npx jest --json");
+}
+
+#[test]
+fn reports_written_to_an_output_file_are_printed() {
+    let tmp = tempfile::tempdir().unwrap();
+    let run = |cmd: &str| {
+        Command::new("sh")
+            .arg("-c")
+            .arg(print_output_file(cmd))
+            .current_dir(tmp.path())
+            .output()
+            .unwrap()
+    };
+    // `true` and `false` ignore the flag, standing in for Jest
+    let report = format!("printf '%s' '{JEST_JSON}' > report.json");
+
+    let output = run(&format!("{report}; false --json --outputFile=report.json"));
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        parse_test_output(&outcome_output(&stdout)).unwrap().len(),
+        2
+    );
+
+    // A report left over from an earlier run is not printed again
+    let output = run("true --json --outputFile=report.json");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(parse_test_output(&outcome_output(&stdout)), None);
+}
+
+#[test]
+fn unrecognized_output_is_not_parsed() {
+    assert_eq!(parse_test_output(&outcome_output("Tests: 3 passed")), None);
+    assert!(parse_test_output(&outcome_output(JEST_JSON)).is_some());
+    assert!(parse_test_output(&outcome_output(TAP)).is_some());
+}
+
+fn mutant(id: i64, stdout: &str) -> MutantTests {
+    MutantTests::from_output(
+        id,
+        "contracts/wallet.tact".to_string(),
+        3,
+        "ER".to_string(),
+        "TestFail".to_string(),
+        &outcome_output(stdout),
+    )
+    .unwrap()
+}
+
+#[test]
+fn report_counts_kills_and_unique_kills_per_test() {
+    let report = EffectivenessReport::new(vec![
+        mutant(1, "not ok 1 - a\nnot ok 2 - b\nok 3 - c\n"),
+        mutant(2, "not ok 1 - a\nok 2 - b\nok 3 - c\n"),
+        mutant(3, "ok 1 - a\nok 2 - b\nok 3 - c\n"),
+    ]);
+
+    assert_eq!(report.mutants[0].killed_by, ["a", "b"]);
+    assert!(report.mutants[2].killed_by.is_empty());
+    let kills = |name: &str, kills, unique_kills| TestKills {
+        name: name.to_string(),
+        kills,
+        unique_kills,
+    };
+    assert_eq!(
+        report.tests,
+        [kills("a", 2, 1), kills("b", 1, 0), kills("c", 0, 0)]
+    );
+
+    let table = report.render_table();
+    assert!(table.contains("b         1       0  redundant"), "{table}");
+    assert!(
+        table.contains("c         0       0  never fails"),
+        "{table}"
+    );
+}
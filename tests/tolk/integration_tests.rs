@@ -140,6 +140,29 @@ fun main(): int {
     );
 }
 
+#[test]
+fn partially_parsed_source_still_yields_mutants() {
+    // Outside strict mode, parse errors are reported and the rest of the file is mutated
+    let source = r#"
+fun broken(): int {
+    return 1 +* 2;
+}
+
+fun main(x: int): int {
+    if (x > 0) {
+        return x;
+    }
+    return 0;
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "IF");
+    assert!(
+        !mutants.is_empty(),
+        "expected mutants outside the unparseable function"
+    );
+}
+
 #[test]
 fn engine_reports_expected_metadata_and_slugs() {
     let engine = TolkLanguageEngine::new();
@@ -150,8 +173,8 @@ fn engine_reports_expected_metadata_and_slugs() {
     let slugs: BTreeSet<_> = engine.get_mutations().iter().map(|m| m.slug).collect();
 
     let expected: BTreeSet<_> = [
-        "AAOS", "AOS", "AS", "BAOS", "BL", "BOS", "COS", "CR", "ER", "IF", "IT", "LC", "LOS", "NR",
-        "SAOS", "SOS", "WF",
+        "AAOS", "AD", "AN", "AOS", "AS", "BAOS", "BL", "BOS", "COS", "CR", "ER", "IF", "IT", "LC",
+        "LOS", "MAE", "MAR", "MAS", "NR", "RZ", "SAOS", "SOS", "TC", "TF", "TT", "UF", "WF",
     ]
     .into_iter()
     .collect();
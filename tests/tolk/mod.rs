@@ -1,2 +1,4 @@
 mod integration_tests;
 mod mutations;
+mod parser_tests;
+mod suppression_tests;
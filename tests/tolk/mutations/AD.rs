@@ -0,0 +1,24 @@
+use crate::tolk::integration_tests::{first_mutated_source, mutants_for_slug};
+
+#[test]
+fn ad_removes_assert_statements() {
+    let source = r#"
+fun withdraw(amount: int) {
+    assert(amount > 0, 101);
+    assert (amount < 100) throw 102;
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "AD");
+    assert_eq!(mutants.len(), 2, "expected one AD mutant per assert");
+    assert!(
+        mutants.iter().all(|m| m.new_text.is_empty()),
+        "expected AD mutants to delete the statement"
+    );
+
+    let mutated = first_mutated_source(source, "AD").expect("AD mutant");
+    assert!(
+        !mutated.contains("101") && !mutated.contains(";;"),
+        "expected AD to remove the assert and its semicolon; mutated source: {mutated}"
+    );
+}
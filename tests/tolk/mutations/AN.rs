@@ -0,0 +1,16 @@
+use crate::tolk::integration_tests::first_mutated_source;
+
+#[test]
+fn an_negates_assert_conditions() {
+    let source = r#"
+fun withdraw(amount: int) {
+    assert(amount > 0, 101);
+}
+"#;
+
+    let mutated = first_mutated_source(source, "AN").expect("AN mutant");
+    assert!(
+        mutated.contains("assert(!(amount > 0), 101)"),
+        "expected AN to negate the assert condition; mutated source: {mutated}"
+    );
+}
@@ -0,0 +1,55 @@
+use crate::tolk::integration_tests::{first_mutated_source, mutants_for_slug};
+
+#[test]
+fn mae_replaces_arm_body_with_else_body() {
+    let source = r#"
+fun onInternalMessage(op: int): int {
+    match (op) {
+        1 => { return 1; }
+        2 => { return 2; }
+        else => { return 0; }
+    }
+}
+"#;
+
+    assert_eq!(mutants_for_slug(source, "MAE").len(), 2);
+    let mutated = first_mutated_source(source, "MAE").expect("MAE mutant");
+    assert!(
+        mutated.contains("1 => { return 0; }"),
+        "expected MAE to use the else body; mutated source: {mutated}"
+    );
+}
+
+#[test]
+fn mae_requires_an_else_arm() {
+    let source = r#"
+fun fee(op: int): int {
+    return match (op) { 1 => 10, 2 => 20 };
+}
+"#;
+
+    assert!(mutants_for_slug(source, "MAE").is_empty());
+}
+
+#[test]
+fn mae_uses_a_throwing_else_arm() {
+    let source = r#"
+fun onInternalMessage(op: int): int {
+    return match (op) {
+        1 => 10,
+        2 => throw 0xFFFE,
+        else => throw 0xFFFF
+    };
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "MAE");
+    let rewrites: Vec<_> = mutants
+        .iter()
+        .map(|m| format!("{} -> {}", m.old_text, m.new_text))
+        .collect();
+    assert_eq!(
+        rewrites,
+        ["10 -> throw 0xFFFF", "throw 0xFFFE -> throw 0xFFFF"]
+    );
+}
@@ -0,0 +1,27 @@
+use crate::tolk::integration_tests::{first_mutated_source, mutants_for_slug};
+
+#[test]
+fn mar_removes_pattern_arms_but_keeps_else() {
+    let source = r#"
+fun onInternalMessage(op: int): int {
+    match (op) {
+        0x01 => { return 1; }
+        0x02 => return 2,
+        else => { return 0; }
+    }
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "MAR");
+    assert_eq!(mutants.len(), 2, "expected one MAR mutant per pattern arm");
+    assert!(
+        mutants.iter().all(|m| !m.old_text.starts_with("else")),
+        "expected MAR to keep the else arm"
+    );
+
+    let mutated = first_mutated_source(source, "MAR").expect("MAR mutant");
+    assert!(
+        !mutated.contains("0x01") && mutated.contains("0x02 => return 2,"),
+        "expected MAR to remove the first arm; mutated source: {mutated}"
+    );
+}
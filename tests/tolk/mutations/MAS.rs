@@ -0,0 +1,49 @@
+use crate::tolk::integration_tests::{first_mutated_source, mutants_for_slug};
+
+#[test]
+fn mas_swaps_adjacent_arm_bodies() {
+    let source = r#"
+fun fee(op: int): int {
+    return match (op) { 1 => 10, 2 => 20, else => 0 };
+}
+"#;
+
+    let mutated = first_mutated_source(source, "MAS").expect("MAS mutant");
+    assert!(
+        mutated.contains("1 => 20, 2 => 10, else => 0"),
+        "expected MAS to swap the arm bodies; mutated source: {mutated}"
+    );
+}
+
+#[test]
+fn mas_skips_arms_with_different_body_shapes() {
+    let source = r#"
+fun onInternalMessage(op: int): int {
+    match (op) {
+        1 => { return 1; }
+        2 => return 2,
+        else => { return 0; }
+    }
+}
+"#;
+
+    assert!(
+        mutants_for_slug(source, "MAS").is_empty(),
+        "expected no MAS mutant between a block body and a comma-terminated body"
+    );
+}
+
+#[test]
+fn mas_swaps_throwing_arm_bodies() {
+    let source = r#"
+fun fee(op: int): int {
+    return match (op) { 1 => 10, 2 => throw 0xFFFF, else => 0 };
+}
+"#;
+
+    let mutated = first_mutated_source(source, "MAS").expect("MAS mutant");
+    assert!(
+        mutated.contains("1 => throw 0xFFFF, 2 => 10, else => 0"),
+        "expected MAS to swap in the throwing body; mutated source: {mutated}"
+    );
+}
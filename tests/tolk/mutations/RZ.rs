@@ -0,0 +1,20 @@
+use crate::tolk::integration_tests::first_mutated_source;
+
+#[test]
+fn rz_replaces_repeat_count_with_zero() {
+    let source = r#"
+fun spin(n: int): int {
+    var total = 0;
+    repeat (n) {
+        total += 1;
+    }
+    return total;
+}
+"#;
+
+    let mutated = first_mutated_source(source, "RZ").expect("RZ mutant");
+    assert!(
+        mutated.contains("repeat (0)"),
+        "expected RZ mutant to repeat zero times; mutated source: {mutated}"
+    );
+}
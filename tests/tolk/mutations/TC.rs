@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+
+use crate::tolk::integration_tests::mutants_for_slug;
+
+#[test]
+fn tc_changes_thrown_exit_codes() {
+    let source = r#"
+const ERR_AUTH = 403;
+
+fun check(amount: int) {
+    assert(amount > 0, 101);
+    assert (amount < 100) throw ERR_AUTH;
+    throw (0x10, amount);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "TC");
+    let rewrites: HashSet<_> = mutants
+        .iter()
+        .map(|m| format!("{} -> {}", m.old_text, m.new_text))
+        .collect();
+    let expected: HashSet<_> = ["101 -> 102", "ERR_AUTH -> ERR_AUTH + 1", "0x10 -> 17"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    assert_eq!(rewrites, expected);
+}
+
+#[test]
+fn tc_parenthesizes_compound_codes_and_skips_overflow() {
+    let source = r#"
+fun check(flag: bool) {
+    throw flag?1:2;
+    assert(flag, 18446744073709551615);
+    assert(flag) throw Errors.NotOwner;
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "TC");
+    let rewrites: Vec<_> = mutants
+        .iter()
+        .map(|m| format!("{} -> {}", m.old_text, m.new_text))
+        .collect();
+    assert_eq!(
+        rewrites,
+        [
+            "flag?1:2 -> (flag?1:2) + 1",
+            "Errors.NotOwner -> Errors.NotOwner + 1"
+        ]
+    );
+}
@@ -0,0 +1,15 @@
+use crate::tolk::integration_tests::mutants_for_slug;
+
+#[test]
+fn tt_replaces_ternary_conditions_with_true() {
+    let source = r#"
+fun pick(a: int): int {
+    return a > 0 ? 1 : 2;
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "TT");
+    assert_eq!(mutants.len(), 1, "expected one TT mutant");
+    assert_eq!(mutants[0].old_text, "a > 0");
+    assert_eq!(mutants[0].new_text, "true");
+}
@@ -0,0 +1,19 @@
+use crate::tolk::integration_tests::first_mutated_source;
+
+#[test]
+fn uf_replaces_do_while_condition_with_false() {
+    let source = r#"
+fun drain(n: int): int {
+    do {
+        n -= 1;
+    } while (n > 0);
+    return n;
+}
+"#;
+
+    let mutated = first_mutated_source(source, "UF").expect("UF mutant");
+    assert!(
+        mutated.contains("while (false)"),
+        "expected UF mutant to hardcode the loop condition to `false`; mutated source: {mutated}"
+    );
+}
@@ -2,6 +2,10 @@
 
 #[path = "AAOS.rs"]
 mod aaos;
+#[path = "AD.rs"]
+mod ad;
+#[path = "AN.rs"]
+mod an;
 #[path = "AOS.rs"]
 mod aos;
 #[path = "AS.rs"]
@@ -26,11 +30,27 @@ mod it;
 mod lc;
 #[path = "LOS.rs"]
 mod los;
+#[path = "MAE.rs"]
+mod mae;
+#[path = "MAR.rs"]
+mod mar;
+#[path = "MAS.rs"]
+mod mas;
 #[path = "NR.rs"]
 mod nr;
+#[path = "RZ.rs"]
+mod rz;
 #[path = "SAOS.rs"]
 mod saos;
 #[path = "SOS.rs"]
 mod sos;
+#[path = "TC.rs"]
+mod tc;
+#[path = "TF.rs"]
+mod tf;
+#[path = "TT.rs"]
+mod tt;
+#[path = "UF.rs"]
+mod uf;
 #[path = "WF.rs"]
 mod wf;
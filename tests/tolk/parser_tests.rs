@@ -0,0 +1,40 @@
+use std::sync::OnceLock;
+
+use mewt::utils::parse_source;
+use muton::languages::scope::{enclosing_function, matches_selector};
+use muton::languages::tolk::patterns::function_spans;
+use tree_sitter::Language as TsLanguage;
+
+static TOLK_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
+
+unsafe extern "C" {
+    fn tree_sitter_tolk() -> *const tree_sitter::ffi::TSLanguage;
+}
+
+fn tolk_language() -> &'static TsLanguage {
+    TOLK_LANGUAGE.get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_tolk()) })
+}
+
+fn parse_tolk(source: &str) -> tree_sitter::Tree {
+    parse_source(source, tolk_language()).expect("Tolk parser returned None")
+}
+
+#[test]
+fn function_spans_include_methods_and_get_methods() {
+    let source = r#"
+fun main(x: int) { return x; }
+fun Point.sum(self): int { return self.x + self.y; }
+get fun seqno(): int { return 1; }
+"#;
+    let tree = parse_tolk(source);
+    let spans = function_spans(tree.root_node(), source);
+
+    let names: Vec<_> = spans.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, ["main", "Point.sum", "seqno"]);
+
+    let inside_sum = source.find("self.x").unwrap();
+    let sum = enclosing_function(&spans, inside_sum).expect("enclosing function");
+    assert!(matches_selector(&sum.name, "Point.sum"));
+    assert!(matches_selector(&sum.name, "sum"));
+    assert!(!matches_selector(&sum.name, "main"));
+}
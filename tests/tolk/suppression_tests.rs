@@ -0,0 +1,32 @@
+use crate::tolk::integration_tests::mutants_for_slug;
+
+#[test]
+fn ignore_start_without_end_runs_to_end_of_file() {
+    let source = r#"
+fun a(x: int) {
+    assert(x > 0, 100);
+}
+
+// muton-ignore-start: AD
+fun b(x: int) {
+    assert(x > 1, 101);
+}
+"#;
+
+    let mutants = mutants_for_slug(source, "AD");
+    assert_eq!(mutants.len(), 1);
+    assert_eq!(mutants[0].line_offset, 2);
+    assert_eq!(mutants_for_slug(source, "AN").len(), 2);
+}
+
+#[test]
+fn unrelated_comments_do_not_suppress_mutants() {
+    let source = r#"
+fun a(x: int) {
+    // muton-ignored is not a directive
+    assert(x > 0, 100);
+}
+"#;
+
+    assert_eq!(mutants_for_slug(source, "AD").len(), 1);
+}
@@ -1,10 +1,11 @@
 #![allow(dead_code)]
 
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
-use mewt::LanguageEngine;
 use mewt::types::{Hash, Mutant, Target};
+use mewt::{LanguageEngine, SqlStore};
 use tempfile::TempDir;
 
 /// Keeps the temporary directory alive for the lifetime of a test target and
@@ -67,6 +68,43 @@ impl TargetFixture {
     }
 }
 
+/// Create an empty `muton.sqlite` database in `dir` and open it.
+pub async fn create_store(dir: &Path) -> SqlStore {
+    let db = dir.join("muton.sqlite");
+    std::fs::File::create(&db).expect("failed to create test database");
+    SqlStore::new(format!("sqlite:{}", db.display()))
+        .await
+        .expect("failed to open test database")
+}
+
+/// Build a [`Target`] for `text` at `path` that is not yet in any database.
+pub fn new_target(path: impl Into<PathBuf>, text: &str, language: &str) -> Target {
+    Target {
+        id: 0,
+        path: path.into(),
+        file_hash: Hash::digest(text.to_string()),
+        text: text.to_string(),
+        language: language.to_string(),
+    }
+}
+
+/// Run `git` in `dir` under a fixed identity, failing the test if it fails.
+pub fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args([
+            "-c",
+            "user.name=muton",
+            "-c",
+            "user.email=muton@example.com",
+        ])
+        .args(args)
+        .status()
+        .expect("failed to run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
 /// Create a test target for a language/extension pair.
 pub fn target_fixture_for_extension(
     language: &str,